@@ -0,0 +1,352 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Procedural macros for the [approx](https://docs.rs/approx) crate.
+
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+#[macro_use]
+extern crate syn;
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::{Block, Error, Expr, Ident, ItemFn, MetaNameValue};
+
+/// The options of `#[approx_test]`, which are the fields of `approx::Defaults`.
+const OPTIONS: &[&str] = &["scale", "epsilon", "max_relative", "max_ulps"];
+
+/// Declare a test that overrides the default tolerances of the comparisons made within it.
+///
+/// This is used in place of `#[test]`, and accepts the fields of `approx::Defaults` as options:
+///
+/// - `scale`: the factor that the default tolerances are multiplied by
+/// - `epsilon`: the tolerance for values that are close together
+/// - `max_relative`: the relative tolerance for values that are far-apart
+/// - `max_ulps`: the ULPs to tolerate for values that are far-apart
+///
+/// The test is run using `approx::with_defaults`, so these become the defaults of the
+/// `Relative::default()` and `Ulps::default()` constructors and of the macros, such as
+/// `assert_relative_eq!` and `assert_ulps_eq!`, for the tolerances that are not given
+/// explicitly. Options that are not given keep the values that would otherwise be used, such as
+/// those read from the `APPROX_TOLERANCE_SCALE` and `APPROX_MAX_ULPS` environment variables.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+/// extern crate approx_macros;
+///
+/// use approx_macros::approx_test;
+///
+/// #[approx_test(max_relative = 1e-6, max_ulps = 8)]
+/// fn test_sqrt() {
+///     assert_relative_eq!(3.0f64.sqrt(), 1.732051);
+///     assert_ulps_eq!(0.1 + 0.2, 0.3);
+/// }
+/// # fn main() {}
+/// ```
+#[proc_macro_attribute]
+pub fn approx_test(args: TokenStream, item: TokenStream) -> TokenStream {
+    match expand(args.into(), item.into()) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand(args: TokenStream2, item: TokenStream2) -> Result<TokenStream2, Error> {
+    let options = Punctuated::<MetaNameValue, Token![,]>::parse_terminated.parse2(args)?;
+    let mut fields = Vec::with_capacity(options.len());
+    let mut seen = Vec::with_capacity(options.len());
+    for option in &options {
+        let name = match option.path.get_ident() {
+            Some(name) if OPTIONS.iter().any(|option| name == option) => name,
+            _ => {
+                return Err(Error::new_spanned(
+                    &option.path,
+                    "unknown option, expected one of `scale`, `epsilon`, `max_relative` or \
+                     `max_ulps`",
+                ))
+            }
+        };
+        if seen.contains(&name) {
+            return Err(Error::new_spanned(
+                name,
+                format!("duplicate option `{}`", name),
+            ));
+        }
+        seen.push(name);
+
+        let value = &option.value;
+        fields.push(if name == "scale" {
+            quote!(#name: #value)
+        } else {
+            quote!(#name: ::std::option::Option::Some(#value))
+        });
+    }
+
+    let mut function: ItemFn = syn::parse2(item)?;
+    if let Some(attr) = function
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("test"))
+    {
+        return Err(Error::new_spanned(
+            attr,
+            "`#[approx_test]` declares the test, so `#[test]` is not needed",
+        ));
+    }
+    if !function.sig.inputs.is_empty() {
+        return Err(Error::new_spanned(
+            &function.sig.inputs,
+            "test functions cannot take arguments",
+        ));
+    }
+
+    let block = &function.block;
+    let block: Block = parse_quote!({
+        ::approx::with_defaults(
+            ::approx::Defaults {
+                #(#fields,)*
+                ..::approx::Defaults::current()
+            },
+            move || #block,
+        )
+    });
+    *function.block = block;
+
+    Ok(quote! {
+        #[test]
+        #function
+    })
+}
+
+/// Define a family of comparison and assertion macros with the options of the comparison baked
+/// in, for use in place of wrappers of the standard macros.
+///
+/// This takes the names of the equality and assertion macros, which must end with `_eq`, and
+/// then the options:
+///
+/// - `mode`: the comparison to use, which is one of `abs_diff`, `relative` (the default) or
+///   `ulps`
+/// - the options of the comparison, such as `epsilon`, `max_relative` or `max_ulps`
+///
+/// For `define_approx_macros!(geom_eq, assert_geom_eq, ...)` the macros that are defined are
+/// `geom_eq!`, `geom_ne!`, `assert_geom_eq!`, `assert_geom_ne!`, `debug_assert_geom_eq!` and
+/// `debug_assert_geom_ne!`. They are used like the standard macros, including accepting options
+/// that override the baked in ones, and the assertions have the standard failure messages.
+///
+/// The macros are defined like `macro_rules!` macros at the point of use, so they are available
+/// to the code that follows, and to child modules declared with `#[macro_use]`. Those of the
+/// family that are not used do not cause warnings.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+/// extern crate approx_macros;
+///
+/// use approx_macros::define_approx_macros;
+///
+/// define_approx_macros!(geom_eq, assert_geom_eq, mode = relative, epsilon = 1e-9, max_relative = 1e-7);
+///
+/// # fn main() {
+/// assert!(geom_eq!(1.0, 1.0 + 1e-8));
+/// assert_geom_eq!(0.0, 1e-10);
+/// assert_geom_ne!(1.0, 1.0 + 1e-8, max_relative = 1e-9);
+/// debug_assert_geom_ne!(1.0, 1.001);
+/// # }
+/// ```
+#[proc_macro]
+pub fn define_approx_macros(input: TokenStream) -> TokenStream {
+    match syn::parse::<Family>(input).and_then(|family| family.expand()) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// The comparisons that can be used by `define_approx_macros!`, and the options they accept.
+const MODES: &[(&str, &str, &[&str])] = &[
+    ("abs_diff", "AbsDiff", &["epsilon"]),
+    ("relative", "Relative", &["epsilon", "max_relative"]),
+    ("ulps", "Ulps", &["epsilon", "max_ulps"]),
+];
+
+/// The input of `define_approx_macros!`.
+struct Family {
+    eq: Ident,
+    assert: Ident,
+    mode: Option<Ident>,
+    options: Vec<(Ident, Expr)>,
+}
+
+impl Parse for Family {
+    fn parse(input: ParseStream) -> syn::Result<Family> {
+        let eq = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let assert = input.parse()?;
+
+        let mut mode = None;
+        let mut options: Vec<(Ident, Expr)> = Vec::new();
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let name: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            let duplicate = if name == "mode" {
+                mode.replace(input.parse()?).is_some()
+            } else {
+                let duplicate = options.iter().any(|option| option.0 == name);
+                options.push((name.clone(), input.parse()?));
+                duplicate
+            };
+            if duplicate {
+                return Err(Error::new_spanned(
+                    &name,
+                    format!("duplicate option `{}`", name),
+                ));
+            }
+        }
+
+        Ok(Family {
+            eq,
+            assert,
+            mode,
+            options,
+        })
+    }
+}
+
+impl Family {
+    fn expand(&self) -> syn::Result<TokenStream2> {
+        let mode = self
+            .mode
+            .as_ref()
+            .map_or("relative".to_string(), Ident::to_string);
+        let (comparison, allowed) = match MODES.iter().find(|m| m.0 == mode) {
+            Some(&(_, comparison, allowed)) => (Ident::new(comparison, Span::call_site()), allowed),
+            None => {
+                return Err(Error::new_spanned(
+                    &self.mode,
+                    "unknown mode, expected one of `abs_diff`, `relative` or `ulps`",
+                ))
+            }
+        };
+        for (name, _) in &self.options {
+            if !allowed.iter().any(|option| name == option) {
+                return Err(Error::new_spanned(
+                    name,
+                    format!("unknown option for the `{}` mode", mode),
+                ));
+            }
+        }
+
+        let eq = &self.eq;
+        let ne = with_ne_suffix(eq)?;
+        let assert_eq = &self.assert;
+        let assert_ne = with_ne_suffix(assert_eq)?;
+        let debug_assert_eq = Ident::new(&format!("debug_{}", assert_eq), assert_eq.span());
+        let debug_assert_ne = Ident::new(&format!("debug_{}", assert_ne), assert_ne.span());
+
+        let comparison = {
+            let names = self.options.iter().map(|option| &option.0);
+            let values = self.options.iter().map(|option| &option.1);
+            quote! {
+                ::approx::#comparison::default()
+                    #(.#names(::approx::__tolerance!(#names, #values)))*
+            }
+        };
+
+        let compare = |name: &Ident, method: &str| {
+            let method = Ident::new(method, Span::call_site());
+            quote! {
+                #[allow(unused_macros)]
+                macro_rules! #name {
+                    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+                        #comparison
+                            $(.$opt(::approx::__tolerance!($opt, $val)))*
+                            .#method(&$lhs, &$rhs)
+                    };
+                    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+                        #name!($lhs, $rhs $(, $opt = $val)*)
+                    };
+                }
+            }
+        };
+        let assert = |name: &Ident, compare: &Ident| {
+            let message = name.to_string();
+            quote! {
+                #[allow(unused_macros)]
+                macro_rules! #name {
+                    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+                        ::approx::__assert_approx!(
+                            @named #message, #compare, $given, $expected $(, $opt = $val)*
+                        )
+                    };
+                    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+                        #name!($given, $expected $(, $opt = $val)*)
+                    };
+                }
+            }
+        };
+        let debug_assert = |name: &Ident, assert: &Ident| {
+            quote! {
+                #[allow(unused_macros)]
+                macro_rules! #name {
+                    ($($arg:tt)*) => {
+                        if cfg!(debug_assertions) {
+                            #assert!($($arg)*);
+                        }
+                    };
+                }
+            }
+        };
+
+        let eq_macro = compare(eq, "eq");
+        let ne_macro = compare(&ne, "ne");
+        let assert_eq_macro = assert(assert_eq, eq);
+        let assert_ne_macro = assert(&assert_ne, &ne);
+        let debug_assert_eq_macro = debug_assert(&debug_assert_eq, assert_eq);
+        let debug_assert_ne_macro = debug_assert(&debug_assert_ne, &assert_ne);
+        Ok(quote! {
+            #eq_macro
+            #ne_macro
+            #assert_eq_macro
+            #assert_ne_macro
+            #debug_assert_eq_macro
+            #debug_assert_ne_macro
+        })
+    }
+}
+
+/// The name of the inequality macro corresponding to an equality macro, such as `geom_ne` for
+/// `geom_eq`.
+fn with_ne_suffix(eq: &Ident) -> syn::Result<Ident> {
+    let name = eq.to_string();
+    if !name.ends_with("_eq") {
+        return Err(Error::new_spanned(eq, "expected a name ending with `_eq`"));
+    }
+    Ok(Ident::new(
+        &format!("{}_ne", &name[..name.len() - 3]),
+        eq.span(),
+    ))
+}
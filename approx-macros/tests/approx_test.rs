@@ -0,0 +1,67 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+extern crate approx_macros;
+
+use approx::{Defaults, Relative, Ulps};
+use approx_macros::approx_test;
+use std::f64;
+
+#[approx_test(max_relative = 1e-6, max_ulps = 8)]
+fn test_options() {
+    assert_eq!(Relative::<f64>::default().max_relative, 1e-6);
+    assert_eq!(Ulps::<f64>::default().max_ulps, 8);
+    assert_relative_eq!(3.0f64.sqrt(), 1.732051);
+    assert_ulps_eq!(1.0, 1.0 + 6.0 * f64::EPSILON, epsilon = 0.0);
+}
+
+#[approx_test(epsilon = 1e-9, scale = 2.0)]
+fn test_scale() {
+    assert_eq!(Defaults::current().scale, 2.0);
+    assert_abs_diff_eq!(0.0, 1.5e-9);
+    assert_abs_diff_ne!(0.0, 1.5e-9, epsilon = 1e-9);
+}
+
+#[approx_test(max_relative = 1e-6)]
+fn test_trailing_comma() {
+    assert_relative_eq!(1.0, 1.0 + 1e-7);
+}
+
+#[approx_test()]
+fn test_no_options() {
+    assert_relative_ne!(1.0, 1.0 + 1e-7);
+}
+
+#[approx_test(max_relative = 1e-12)]
+#[should_panic(expected = "max_relative = 1e-12")]
+fn test_failure_message() {
+    assert_relative_eq!(1.0, 1.0 + 1e-7);
+}
+
+#[approx_test(max_relative = 0.5)]
+fn test_result() -> Result<(), String> {
+    if relative_eq!(1.0, 1.4) {
+        return Ok(());
+    }
+    Err("not equal".to_string())
+}
+
+#[test]
+fn test_restored() {
+    let current = Defaults::current();
+    test_options();
+    assert_eq!(Defaults::current(), current);
+}
@@ -0,0 +1,111 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate approx_macros;
+
+use approx_macros::define_approx_macros;
+
+define_approx_macros!(
+    geom_eq,
+    assert_geom_eq,
+    mode = relative,
+    epsilon = 1e-9,
+    max_relative = 1e-7
+);
+define_approx_macros!(close_eq, assert_close_eq, mode = abs_diff, epsilon = 0.5,);
+define_approx_macros!(
+    bits_eq,
+    assert_bits_eq,
+    mode = ulps,
+    epsilon = 0.0,
+    max_ulps = 2
+);
+define_approx_macros!(default_eq, assert_default_eq);
+
+mod test_relative {
+    #[test]
+    fn test_compare() {
+        assert!(geom_eq!(0.0, 1e-10));
+        assert!(geom_eq!(1e3, 1e3 + 1e-5));
+        assert!(geom_ne!(1e3, 1e3 + 1e-3));
+        assert!(geom_ne!(1e3, 1e3 + 1e-5, max_relative = 1e-9));
+        assert!(geom_eq!(1.0, 1.1, max_relative = 0.1,));
+    }
+
+    #[test]
+    fn test_assert() {
+        assert_geom_eq!(1.0, 1.0 + 1e-8);
+        assert_geom_ne!(1.0, 1.0 + 1e-8, max_relative = 1e-9);
+        assert_geom_eq!(&[1.0, 2.0][..], &[1.0, 2.0 + 1e-8][..]);
+        debug_assert_geom_eq!(1.0, 1.0 + 1e-8);
+        debug_assert_geom_ne!(1.0, 1.0 + 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "assert_geom_eq!(1.0, 1.001)
+
+    left  = 1.0
+    right = 1.001
+")]
+    fn test_message() {
+        assert_geom_eq!(1.0, 1.001);
+    }
+
+    #[test]
+    #[should_panic(expected = "assert_geom_ne!(1.0, 1.0, epsilon = 0.5)")]
+    fn test_message_options() {
+        assert_geom_ne!(1.0, 1.0, epsilon = 0.5);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "assert_geom_eq!(x, 1.0, max_relative = 1e-12)")]
+    fn test_debug_assert() {
+        fn check(x: f64) {
+            debug_assert_geom_eq!(x, 1.0, max_relative = 1e-12);
+        }
+        check(1.1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "invalid tolerance `epsilon = -1.0`")]
+    fn test_validated() {
+        let _ = geom_eq!(1.0, 1.0, epsilon = -1.0);
+    }
+}
+
+mod test_modes {
+    use std::f64;
+
+    #[test]
+    fn test_abs_diff() {
+        assert_close_eq!(1.0, 1.4);
+        assert_close_ne!(1.0, 1.6);
+    }
+
+    #[test]
+    fn test_ulps() {
+        assert_bits_eq!(1.0, 1.0 + 2.0 * f64::EPSILON);
+        assert_bits_ne!(1.0, 1.0 + 3.0 * f64::EPSILON);
+        assert_bits_eq!(1.0, 1.0 + 3.0 * f64::EPSILON, max_ulps = 3);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_default_eq!(1.0, 1.0 + f64::EPSILON);
+        assert_default_ne!(1.0, 1.0 + 1e-9);
+    }
+}
@@ -5,6 +5,8 @@ use num_complex::Complex;
 use num_traits::Float;
 #[cfg(feature = "ordered-float")]
 use ordered_float::{NotNan, OrderedFloat};
+#[cfg(feature = "std")]
+use std::string::String;
 
 /// Equality that is defined using the absolute difference of two numbers.
 /// 
@@ -23,7 +25,49 @@ use ordered_float::{NotNan, OrderedFloat};
 /// assert_abs_diff_ne!(1.0f32, 1.0000001f32, epsilon = 1e-8);
 /// # }
 /// ```
-pub trait AbsDiffEq<Rhs = Self>: PartialEq<Rhs>
+///
+/// Implementing this trait does not require implementing `PartialEq`, so it can be implemented
+/// for types whose exact equality is meaningless or unavailable. Generic code that also compares
+/// values exactly should require `PartialEq` separately.
+///
+/// # Implementing for composite types
+///
+/// The overrides of the default tolerances, such as those of `approx::with_defaults`, are applied
+/// using [`AbsDiffEq::scale_epsilon`] and [`AbsDiffEq::epsilon_from_f64`], whose default
+/// implementations return `None` so that the overrides are not applied. Types whose `Epsilon` is
+/// that of another type, such as vectors and matrices of floats, should forward both methods to
+/// that type for the overrides to apply to them. The failure messages of the assertion macros
+/// only show the overrides that were applied:
+///
+/// ```
+/// use approx::AbsDiffEq;
+///
+/// struct Point {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// impl AbsDiffEq for Point {
+///     type Epsilon = f64;
+///
+///     fn default_epsilon() -> f64 {
+///         f64::default_epsilon()
+///     }
+///
+///     fn scale_epsilon(epsilon: &f64, scale: f64) -> Option<f64> {
+///         f64::scale_epsilon(epsilon, scale)
+///     }
+///
+///     fn epsilon_from_f64(value: f64) -> Option<f64> {
+///         f64::epsilon_from_f64(value)
+///     }
+///
+///     fn abs_diff_eq(&self, other: &Point, epsilon: f64) -> bool {
+///         self.x.abs_diff_eq(&other.x, epsilon) && self.y.abs_diff_eq(&other.y, epsilon)
+///     }
+/// }
+/// ```
+pub trait AbsDiffEq<Rhs = Self>
 where
     Rhs: ?Sized,
 {
@@ -36,6 +80,31 @@ where
     /// or [`ulps_eq!`] macros.
     fn default_epsilon() -> Self::Epsilon;
 
+    /// Multiply a default tolerance by `scale`, as requested by `approx::with_defaults` or the
+    /// `APPROX_TOLERANCE_SCALE` environment variable, or return `None` if the tolerance cannot be
+    /// scaled.
+    ///
+    /// The default implementation returns `None`, so the override is not applied, and
+    /// implementations for types that delegate to another type's comparisons should forward to
+    /// its implementation, as described [above](AbsDiffEq#implementing-for-composite-types).
+    #[inline]
+    fn scale_epsilon(epsilon: &Self::Epsilon, scale: f64) -> Option<Self::Epsilon> {
+        let _ = (epsilon, scale);
+        None
+    }
+
+    /// Convert a default tolerance given as an `f64`, such as by an `approx::Defaults` override,
+    /// or return `None` if the tolerance has no such representation.
+    ///
+    /// The default implementation returns `None`, so the override is not applied, and
+    /// implementations for types that delegate to another type's comparisons should forward to
+    /// its implementation, as described [above](AbsDiffEq#implementing-for-composite-types).
+    #[inline]
+    fn epsilon_from_f64(value: f64) -> Option<Self::Epsilon> {
+        let _ = value;
+        None
+    }
+
     /// A test for equality that uses the absolute difference to compute the approximate
     /// equality of two numbers.
     fn abs_diff_eq(&self, other: &Rhs, epsilon: Self::Epsilon) -> bool;
@@ -60,6 +129,16 @@ macro_rules! impl_unsigned_abs_diff_eq {
                 $default_epsilon
             }
 
+            #[inline]
+            fn scale_epsilon(epsilon: &$T, scale: f64) -> Option<$T> {
+                Some((*epsilon as f64 * scale) as $T)
+            }
+
+            #[inline]
+            fn epsilon_from_f64(value: f64) -> Option<$T> {
+                Some(value as $T)
+            }
+
             #[inline]
             fn abs_diff_eq(&self, other: &$T, epsilon: $T) -> bool {
                 (if self > other {
@@ -88,6 +167,16 @@ macro_rules! impl_signed_abs_diff_eq {
                 $default_epsilon
             }
 
+            #[inline]
+            fn scale_epsilon(epsilon: &$T, scale: f64) -> Option<$T> {
+                Some((*epsilon as f64 * scale) as $T)
+            }
+
+            #[inline]
+            fn epsilon_from_f64(value: f64) -> Option<$T> {
+                Some(value as $T)
+            }
+
             #[inline]
             #[allow(unused_imports)]
             fn abs_diff_eq(&self, other: &$T, epsilon: $T) -> bool {
@@ -106,6 +195,35 @@ impl_signed_abs_diff_eq!(isize, 0);
 impl_signed_abs_diff_eq!(f32, core::f32::EPSILON);
 impl_signed_abs_diff_eq!(f64, core::f64::EPSILON);
 
+// Non-numeric types are compared exactly, so that they can be used alongside numbers in the
+// implementations for composite types. Their epsilon is ignored.
+macro_rules! impl_exact_abs_diff_eq {
+    ($T:ty) => {
+        impl AbsDiffEq for $T {
+            type Epsilon = ();
+
+            #[inline]
+            fn default_epsilon() {}
+
+            #[inline]
+            fn epsilon_from_f64(_: f64) -> Option<()> {
+                Some(())
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$T, _: ()) -> bool {
+                self == other
+            }
+        }
+    };
+}
+
+impl_exact_abs_diff_eq!(bool);
+impl_exact_abs_diff_eq!(char);
+impl_exact_abs_diff_eq!(str);
+#[cfg(feature = "std")]
+impl_exact_abs_diff_eq!(String);
+
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // Derived implementations
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -118,6 +236,16 @@ impl<'a, T: AbsDiffEq + ?Sized> AbsDiffEq for &'a T {
         T::default_epsilon()
     }
 
+    #[inline]
+    fn scale_epsilon(epsilon: &T::Epsilon, scale: f64) -> Option<T::Epsilon> {
+        T::scale_epsilon(epsilon, scale)
+    }
+
+    #[inline]
+    fn epsilon_from_f64(value: f64) -> Option<T::Epsilon> {
+        T::epsilon_from_f64(value)
+    }
+
     #[inline]
     fn abs_diff_eq(&self, other: &&'a T, epsilon: T::Epsilon) -> bool {
         T::abs_diff_eq(*self, *other, epsilon)
@@ -132,6 +260,16 @@ impl<'a, T: AbsDiffEq + ?Sized> AbsDiffEq for &'a mut T {
         T::default_epsilon()
     }
 
+    #[inline]
+    fn scale_epsilon(epsilon: &T::Epsilon, scale: f64) -> Option<T::Epsilon> {
+        T::scale_epsilon(epsilon, scale)
+    }
+
+    #[inline]
+    fn epsilon_from_f64(value: f64) -> Option<T::Epsilon> {
+        T::epsilon_from_f64(value)
+    }
+
     #[inline]
     fn abs_diff_eq(&self, other: &&'a mut T, epsilon: T::Epsilon) -> bool {
         T::abs_diff_eq(*self, *other, epsilon)
@@ -146,6 +284,16 @@ impl<T: AbsDiffEq + Copy> AbsDiffEq for cell::Cell<T> {
         T::default_epsilon()
     }
 
+    #[inline]
+    fn scale_epsilon(epsilon: &T::Epsilon, scale: f64) -> Option<T::Epsilon> {
+        T::scale_epsilon(epsilon, scale)
+    }
+
+    #[inline]
+    fn epsilon_from_f64(value: f64) -> Option<T::Epsilon> {
+        T::epsilon_from_f64(value)
+    }
+
     #[inline]
     fn abs_diff_eq(&self, other: &cell::Cell<T>, epsilon: T::Epsilon) -> bool {
         T::abs_diff_eq(&self.get(), &other.get(), epsilon)
@@ -160,6 +308,16 @@ impl<T: AbsDiffEq + ?Sized> AbsDiffEq for cell::RefCell<T> {
         T::default_epsilon()
     }
 
+    #[inline]
+    fn scale_epsilon(epsilon: &T::Epsilon, scale: f64) -> Option<T::Epsilon> {
+        T::scale_epsilon(epsilon, scale)
+    }
+
+    #[inline]
+    fn epsilon_from_f64(value: f64) -> Option<T::Epsilon> {
+        T::epsilon_from_f64(value)
+    }
+
     #[inline]
     fn abs_diff_eq(&self, other: &cell::RefCell<T>, epsilon: T::Epsilon) -> bool {
         T::abs_diff_eq(&self.borrow(), &other.borrow(), epsilon)
@@ -178,6 +336,16 @@ where
         A::default_epsilon()
     }
 
+    #[inline]
+    fn scale_epsilon(epsilon: &A::Epsilon, scale: f64) -> Option<A::Epsilon> {
+        A::scale_epsilon(epsilon, scale)
+    }
+
+    #[inline]
+    fn epsilon_from_f64(value: f64) -> Option<A::Epsilon> {
+        A::epsilon_from_f64(value)
+    }
+
     #[inline]
     fn abs_diff_eq(&self, other: &[B], epsilon: A::Epsilon) -> bool {
         self.len() == other.len()
@@ -197,6 +365,16 @@ where
         T::default_epsilon()
     }
 
+    #[inline]
+    fn scale_epsilon(epsilon: &T::Epsilon, scale: f64) -> Option<T::Epsilon> {
+        T::scale_epsilon(epsilon, scale)
+    }
+
+    #[inline]
+    fn epsilon_from_f64(value: f64) -> Option<T::Epsilon> {
+        T::epsilon_from_f64(value)
+    }
+
     #[inline]
     fn abs_diff_eq(&self, other: &Complex<T>, epsilon: T::Epsilon) -> bool {
         T::abs_diff_eq(&self.re, &other.re, epsilon.clone())
@@ -213,6 +391,16 @@ impl<T: AbsDiffEq + Copy> AbsDiffEq for NotNan<T> {
         T::default_epsilon()
     }
 
+    #[inline]
+    fn scale_epsilon(epsilon: &Self::Epsilon, scale: f64) -> Option<Self::Epsilon> {
+        T::scale_epsilon(epsilon, scale)
+    }
+
+    #[inline]
+    fn epsilon_from_f64(value: f64) -> Option<Self::Epsilon> {
+        T::epsilon_from_f64(value)
+    }
+
     #[inline]
     fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
         T::abs_diff_eq(&self.into_inner(), &other.into_inner(), epsilon)
@@ -228,6 +416,16 @@ impl<T: AbsDiffEq + Float> AbsDiffEq<T> for NotNan<T> {
         T::default_epsilon()
     }
 
+    #[inline]
+    fn scale_epsilon(epsilon: &Self::Epsilon, scale: f64) -> Option<Self::Epsilon> {
+        T::scale_epsilon(epsilon, scale)
+    }
+
+    #[inline]
+    fn epsilon_from_f64(value: f64) -> Option<Self::Epsilon> {
+        T::epsilon_from_f64(value)
+    }
+
     #[inline]
     fn abs_diff_eq(&self, other: &T, epsilon: Self::Epsilon) -> bool {
         T::abs_diff_eq(&self.into_inner(), other, epsilon)
@@ -243,6 +441,16 @@ impl<T: AbsDiffEq + Float> AbsDiffEq for OrderedFloat<T> {
         T::default_epsilon()
     }
 
+    #[inline]
+    fn scale_epsilon(epsilon: &Self::Epsilon, scale: f64) -> Option<Self::Epsilon> {
+        T::scale_epsilon(epsilon, scale)
+    }
+
+    #[inline]
+    fn epsilon_from_f64(value: f64) -> Option<Self::Epsilon> {
+        T::epsilon_from_f64(value)
+    }
+
     #[inline]
     fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
         T::abs_diff_eq(&self.into_inner(), &other.into_inner(), epsilon)
@@ -258,6 +466,16 @@ impl<T: AbsDiffEq + Float> AbsDiffEq<T> for OrderedFloat<T> {
         T::default_epsilon()
     }
 
+    #[inline]
+    fn scale_epsilon(epsilon: &Self::Epsilon, scale: f64) -> Option<Self::Epsilon> {
+        T::scale_epsilon(epsilon, scale)
+    }
+
+    #[inline]
+    fn epsilon_from_f64(value: f64) -> Option<Self::Epsilon> {
+        T::epsilon_from_f64(value)
+    }
+
     #[inline]
     fn abs_diff_eq(&self, other: &T, epsilon: Self::Epsilon) -> bool {
         T::abs_diff_eq(&self.into_inner(), other, epsilon)
@@ -0,0 +1,151 @@
+use core::fmt;
+#[cfg(feature = "std")]
+use std::string::String;
+use {AbsDiff, AbsDiffEq, Comparator, Relative, RelativeEq, Ulps, UlpsEq};
+
+/// An expected value that compares equal to the values that are approximately equal to it, using
+/// `==`.
+///
+/// This allows approximate comparisons to be used with code that only understands `PartialEq`,
+/// such as `assert_eq!` or the comparison of vectors. The wrapped value is compared with `T` on
+/// either side for the primitive types and `String`. For other types it can only be on the left,
+/// as the orphan rules do not allow `PartialEq<Approx<T, C>>` to be implemented for every `T`;
+/// it can be implemented for your own types the same way.
+///
+/// The `Debug` output shows the expected value and its comparison, such as
+/// `1.0 ± AbsDiff { epsilon: 0.001 }`, so that it is shown by `assert_eq!` on failure.
+///
+/// # Example
+///
+/// ```rust
+/// use approx::Approx;
+///
+/// assert_eq!(0.1 + 0.2, Approx::relative(0.3));
+/// assert_eq!(Approx::abs_diff(1.0).epsilon(0.1), 1.05);
+/// assert_ne!(1.0f32, Approx::ulps(1.1f32).max_ulps(8));
+///
+/// let expected: Vec<_> = [0.3, 0.7].iter().map(|&x| Approx::relative(x)).collect();
+/// assert_eq!(vec![0.1 + 0.2, 0.2 + 0.5], expected);
+///
+/// assert_eq!(
+///     format!("{:?}", Approx::abs_diff(1.0).epsilon(0.001)),
+///     "1.0 ± AbsDiff { epsilon: 0.001 }"
+/// );
+/// ```
+pub struct Approx<T, C> {
+    /// The expected value.
+    pub expected: T,
+    /// The comparison used to compare values with the expected value.
+    pub comparator: C,
+}
+
+impl<T, C> Approx<T, C> {
+    /// Compare values with the expected value using a comparison, such as a combination of
+    /// comparisons using the [`Comparator`] trait.
+    #[inline]
+    pub fn new(expected: T, comparator: C) -> Approx<T, C> {
+        Approx {
+            expected,
+            comparator,
+        }
+    }
+}
+
+impl<T: AbsDiffEq> Approx<T, AbsDiff<T>> {
+    /// Compare values with the expected value using an absolute difference comparison.
+    #[inline]
+    pub fn abs_diff(expected: T) -> Approx<T, AbsDiff<T>> {
+        Approx::new(expected, AbsDiff::default())
+    }
+
+    /// Replace the epsilon value with the one specified.
+    #[inline]
+    pub fn epsilon(self, epsilon: T::Epsilon) -> Approx<T, AbsDiff<T>> {
+        Approx::new(self.expected, self.comparator.epsilon(epsilon))
+    }
+}
+
+impl<T: RelativeEq> Approx<T, Relative<T>> {
+    /// Compare values with the expected value using a relative comparison.
+    #[inline]
+    pub fn relative(expected: T) -> Approx<T, Relative<T>> {
+        Approx::new(expected, Relative::default())
+    }
+
+    /// Replace the epsilon value with the one specified.
+    #[inline]
+    pub fn epsilon(self, epsilon: T::Epsilon) -> Approx<T, Relative<T>> {
+        Approx::new(self.expected, self.comparator.epsilon(epsilon))
+    }
+
+    /// Replace the maximum relative value with the one specified.
+    #[inline]
+    pub fn max_relative(self, max_relative: T::Epsilon) -> Approx<T, Relative<T>> {
+        Approx::new(self.expected, self.comparator.max_relative(max_relative))
+    }
+}
+
+impl<T: UlpsEq> Approx<T, Ulps<T>> {
+    /// Compare values with the expected value using an ULPs comparison.
+    #[inline]
+    pub fn ulps(expected: T) -> Approx<T, Ulps<T>> {
+        Approx::new(expected, Ulps::default())
+    }
+
+    /// Replace the epsilon value with the one specified.
+    #[inline]
+    pub fn epsilon(self, epsilon: T::Epsilon) -> Approx<T, Ulps<T>> {
+        Approx::new(self.expected, self.comparator.epsilon(epsilon))
+    }
+
+    /// Replace the max ulps value with the one specified.
+    #[inline]
+    pub fn max_ulps(self, max_ulps: u32) -> Approx<T, Ulps<T>> {
+        Approx::new(self.expected, self.comparator.max_ulps(max_ulps))
+    }
+}
+
+impl<T, C> PartialEq<T> for Approx<T, C>
+where
+    C: Comparator<T>,
+{
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        self.comparator.compare(other, &self.expected)
+    }
+}
+
+macro_rules! impl_reverse_partial_eq {
+    ($T:ident) => {
+        impl<C: Comparator<$T>> PartialEq<Approx<$T, C>> for $T {
+            #[inline]
+            fn eq(&self, other: &Approx<$T, C>) -> bool {
+                other == self
+            }
+        }
+    };
+}
+
+impl_reverse_partial_eq!(f32);
+impl_reverse_partial_eq!(f64);
+impl_reverse_partial_eq!(i8);
+impl_reverse_partial_eq!(i16);
+impl_reverse_partial_eq!(i32);
+impl_reverse_partial_eq!(i64);
+impl_reverse_partial_eq!(isize);
+impl_reverse_partial_eq!(u8);
+impl_reverse_partial_eq!(u16);
+impl_reverse_partial_eq!(u32);
+impl_reverse_partial_eq!(u64);
+impl_reverse_partial_eq!(usize);
+impl_reverse_partial_eq!(bool);
+impl_reverse_partial_eq!(char);
+#[cfg(feature = "std")]
+impl_reverse_partial_eq!(String);
+
+/// Formats the expected value and its comparison, such as `1.0 ± AbsDiff { epsilon: 0.001 }`.
+impl<T: fmt::Debug, C: fmt::Debug> fmt::Debug for Approx<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?} ± {:?}", self.expected, self.comparator)
+    }
+}
@@ -0,0 +1,141 @@
+use {
+    AbsDiff, AbsDiffEq, And, Comparator, Not, Or, Periodic, PeriodicEq, Relative, RelativeEq,
+    ScaledRelative, ScaledUlps, Tolerance, Ulps, UlpsEq,
+};
+#[cfg(feature = "std")]
+use {LogRelative, LogRelativeEq};
+
+/// Approximate equality under a tolerance of type `Tol`.
+///
+/// Rather than a trait for each kind of comparison, the kind of comparison is selected by the
+/// type of the tolerance. This is implemented for every type that implements the existing traits,
+/// with the comparison structs such as [`AbsDiff`], [`Relative`] and [`Ulps`] as the tolerances,
+/// and for their combinations using [`And`], [`Or`] and [`Not`]. A new kind of comparison only
+/// needs a new tolerance type, which can then be combined with the existing ones.
+///
+/// # Example
+///
+/// ```rust
+/// use approx::{AbsDiff, ApproxEq, Or, Relative};
+///
+/// /// Values within a number of decibels of each other.
+/// struct Decibels(f64);
+///
+/// impl ApproxEq<f64, Decibels> for f64 {
+///     fn approx_eq(&self, other: &f64, tolerance: &Decibels) -> bool {
+///         (20.0 * (self / other).log10()).abs() <= tolerance.0
+///     }
+/// }
+///
+/// assert!(1.0f64.approx_eq(&1.1, &Decibels(1.0)));
+/// assert!(1.0f64.approx_ne(&2.0, &Decibels(1.0)));
+///
+/// assert!(1.0f64.approx_eq(&1.05, &AbsDiff::default().epsilon(0.1)));
+/// assert!(1.0f64.approx_ne(&1.05, &Relative::default()));
+///
+/// let either = Or(AbsDiff::default().epsilon(0.01), Decibels(1.0));
+/// assert!(0.0f64.approx_eq(&0.001, &either));
+/// assert!(10.0f64.approx_eq(&11.0, &either));
+/// ```
+pub trait ApproxEq<Rhs: ?Sized, Tol: ?Sized> {
+    /// Peform the equality comparison
+    fn approx_eq(&self, other: &Rhs, tolerance: &Tol) -> bool;
+
+    /// Peform the inequality comparison
+    #[inline]
+    fn approx_ne(&self, other: &Rhs, tolerance: &Tol) -> bool {
+        !self.approx_eq(other, tolerance)
+    }
+}
+
+macro_rules! impl_approx_eq {
+    ($Tol:ident, $Trait:ident) => {
+        impl<A, B> ApproxEq<B, $Tol<A, B>> for A
+        where
+            A: $Trait<B> + ?Sized,
+            B: ?Sized,
+            $Tol<A, B>: Comparator<A, B>,
+        {
+            #[inline]
+            fn approx_eq(&self, other: &B, tolerance: &$Tol<A, B>) -> bool {
+                tolerance.compare(self, other)
+            }
+        }
+    };
+}
+
+impl_approx_eq!(AbsDiff, AbsDiffEq);
+impl_approx_eq!(Relative, RelativeEq);
+impl_approx_eq!(Ulps, UlpsEq);
+impl_approx_eq!(ScaledRelative, RelativeEq);
+impl_approx_eq!(ScaledUlps, UlpsEq);
+
+impl<A, B> ApproxEq<B, Periodic<A, B>> for A
+where
+    A: PeriodicEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: Clone,
+{
+    #[inline]
+    fn approx_eq(&self, other: &B, tolerance: &Periodic<A, B>) -> bool {
+        tolerance.clone().eq(self, other)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B> ApproxEq<B, LogRelative<A, B>> for A
+where
+    A: LogRelativeEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: Clone,
+{
+    #[inline]
+    fn approx_eq(&self, other: &B, tolerance: &LogRelative<A, B>) -> bool {
+        tolerance.clone().eq(self, other)
+    }
+}
+
+impl<A, B> ApproxEq<B, Tolerance<A::Epsilon>> for A
+where
+    A: RelativeEq<B> + UlpsEq<B> + PartialEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: Clone,
+{
+    #[inline]
+    fn approx_eq(&self, other: &B, tolerance: &Tolerance<A::Epsilon>) -> bool {
+        tolerance.eq(self, other)
+    }
+}
+
+impl<A, B, C, D> ApproxEq<B, And<C, D>> for A
+where
+    A: ApproxEq<B, C> + ApproxEq<B, D> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn approx_eq(&self, other: &B, tolerance: &And<C, D>) -> bool {
+        self.approx_eq(other, &tolerance.0) && self.approx_eq(other, &tolerance.1)
+    }
+}
+
+impl<A, B, C, D> ApproxEq<B, Or<C, D>> for A
+where
+    A: ApproxEq<B, C> + ApproxEq<B, D> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn approx_eq(&self, other: &B, tolerance: &Or<C, D>) -> bool {
+        self.approx_eq(other, &tolerance.0) || self.approx_eq(other, &tolerance.1)
+    }
+}
+
+impl<A, B, C> ApproxEq<B, Not<C>> for A
+where
+    A: ApproxEq<B, C> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn approx_eq(&self, other: &B, tolerance: &Not<C>) -> bool {
+        !self.approx_eq(other, &tolerance.0)
+    }
+}
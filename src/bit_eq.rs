@@ -0,0 +1,242 @@
+use core::{cell, fmt, mem};
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+#[cfg(feature = "ordered-float")]
+use num_traits::Float;
+#[cfg(feature = "ordered-float")]
+use ordered_float::{NotNan, OrderedFloat};
+
+/// Equality comparisons between the underlying bit patterns of two values.
+///
+/// Unlike `PartialEq`, a `NaN` is equal to another `NaN` with the same payload, and `0.0` is not
+/// equal to `-0.0`. This is useful for checking that a computation is reproducible down to the
+/// last bit, for example across platforms or compiler versions.
+///
+/// `bit_eq`, `bit_ne`, `assert_bit_eq`, and `assert_bit_ne` macros are all wrappers of the
+/// `bit_eq` function in this trait.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate approx;
+/// # use std::f64;
+/// # fn main() {
+/// assert_bit_eq!(f64::NAN, f64::NAN);
+/// assert_bit_ne!(0.0f64, -0.0f64);
+/// # }
+/// ```
+pub trait BitEq<Rhs = Self>
+where
+    Rhs: ?Sized,
+{
+    /// A test for equality of the bit patterns of two values.
+    fn bit_eq(&self, other: &Rhs) -> bool;
+
+    /// The inverse of [`BitEq::bit_eq`].
+    fn bit_ne(&self, other: &Rhs) -> bool {
+        !Self::bit_eq(self, other)
+    }
+
+    /// Formats the bit pattern of the value in hexadecimal.
+    ///
+    /// This is used by the `assert_bit_eq!` and `assert_bit_ne!` macros to report the
+    /// patterns that were compared.
+    fn fmt_bits(&self, f: &mut fmt::Formatter) -> fmt::Result;
+}
+
+/// Formats the bit pattern of a value using [`BitEq::fmt_bits`].
+///
+/// # Example
+///
+/// ```rust
+/// use approx::Bits;
+///
+/// assert_eq!(format!("{:?}", Bits(&1.0f32)), "0x3f800000");
+/// assert_eq!(format!("{:?}", Bits(&[1u8, 2u8][..])), "[0x01, 0x02]");
+/// ```
+pub struct Bits<'a, T: BitEq + ?Sized + 'a>(pub &'a T);
+
+impl<'a, T: BitEq + ?Sized> fmt::Debug for Bits<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        T::fmt_bits(self.0, f)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Base implementations
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! impl_int_bit_eq {
+    ($T:ident) => {
+        impl BitEq for $T {
+            #[inline]
+            fn bit_eq(&self, other: &$T) -> bool {
+                self == other
+            }
+
+            fn fmt_bits(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(
+                    f,
+                    "{:#0width$x}",
+                    self,
+                    width = 2 + 2 * mem::size_of::<$T>()
+                )
+            }
+        }
+    };
+}
+
+impl_int_bit_eq!(u8);
+impl_int_bit_eq!(u16);
+impl_int_bit_eq!(u32);
+impl_int_bit_eq!(u64);
+impl_int_bit_eq!(usize);
+impl_int_bit_eq!(i8);
+impl_int_bit_eq!(i16);
+impl_int_bit_eq!(i32);
+impl_int_bit_eq!(i64);
+impl_int_bit_eq!(isize);
+
+macro_rules! impl_float_bit_eq {
+    ($T:ident) => {
+        impl BitEq for $T {
+            #[inline]
+            fn bit_eq(&self, other: &$T) -> bool {
+                self.to_bits() == other.to_bits()
+            }
+
+            fn fmt_bits(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.to_bits().fmt_bits(f)
+            }
+        }
+    };
+}
+
+impl_float_bit_eq!(f32);
+impl_float_bit_eq!(f64);
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Derived implementations
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'a, T: BitEq + ?Sized> BitEq for &'a T {
+    #[inline]
+    fn bit_eq(&self, other: &&'a T) -> bool {
+        T::bit_eq(*self, *other)
+    }
+
+    fn fmt_bits(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        T::fmt_bits(*self, f)
+    }
+}
+
+impl<'a, T: BitEq + ?Sized> BitEq for &'a mut T {
+    #[inline]
+    fn bit_eq(&self, other: &&'a mut T) -> bool {
+        T::bit_eq(*self, *other)
+    }
+
+    fn fmt_bits(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        T::fmt_bits(*self, f)
+    }
+}
+
+impl<T: BitEq + Copy> BitEq for cell::Cell<T> {
+    #[inline]
+    fn bit_eq(&self, other: &cell::Cell<T>) -> bool {
+        T::bit_eq(&self.get(), &other.get())
+    }
+
+    fn fmt_bits(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        T::fmt_bits(&self.get(), f)
+    }
+}
+
+impl<T: BitEq + ?Sized> BitEq for cell::RefCell<T> {
+    #[inline]
+    fn bit_eq(&self, other: &cell::RefCell<T>) -> bool {
+        T::bit_eq(&self.borrow(), &other.borrow())
+    }
+
+    fn fmt_bits(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        T::fmt_bits(&self.borrow(), f)
+    }
+}
+
+impl<A, B> BitEq<[B]> for [A]
+where
+    A: BitEq<B> + BitEq,
+{
+    #[inline]
+    fn bit_eq(&self, other: &[B]) -> bool {
+        self.len() == other.len()
+            && Iterator::zip(self.iter(), other).all(|(x, y)| BitEq::<B>::bit_eq(x, y))
+    }
+
+    fn fmt_bits(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter().map(Bits)).finish()
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl<T: BitEq> BitEq for Complex<T> {
+    #[inline]
+    fn bit_eq(&self, other: &Complex<T>) -> bool {
+        T::bit_eq(&self.re, &other.re) && T::bit_eq(&self.im, &other.im)
+    }
+
+    fn fmt_bits(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Complex")
+            .field("re", &Bits(&self.re))
+            .field("im", &Bits(&self.im))
+            .finish()
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+impl<T: BitEq + Copy> BitEq for NotNan<T> {
+    #[inline]
+    fn bit_eq(&self, other: &Self) -> bool {
+        T::bit_eq(&self.into_inner(), &other.into_inner())
+    }
+
+    fn fmt_bits(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        T::fmt_bits(&self.into_inner(), f)
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+impl<T: BitEq + Float> BitEq<T> for NotNan<T> {
+    #[inline]
+    fn bit_eq(&self, other: &T) -> bool {
+        T::bit_eq(&self.into_inner(), other)
+    }
+
+    fn fmt_bits(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        T::fmt_bits(&self.into_inner(), f)
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+impl<T: BitEq + Float> BitEq for OrderedFloat<T> {
+    #[inline]
+    fn bit_eq(&self, other: &Self) -> bool {
+        T::bit_eq(&self.into_inner(), &other.into_inner())
+    }
+
+    fn fmt_bits(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        T::fmt_bits(&self.into_inner(), f)
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+impl<T: BitEq + Float> BitEq<T> for OrderedFloat<T> {
+    #[inline]
+    fn bit_eq(&self, other: &T) -> bool {
+        T::bit_eq(&self.into_inner(), other)
+    }
+
+    fn fmt_bits(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        T::fmt_bits(&self.into_inner(), f)
+    }
+}
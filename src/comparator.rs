@@ -0,0 +1,222 @@
+use num_traits::float::FloatCore;
+use {AbsDiff, AbsDiffEq, Relative, RelativeEq, ScaledRelative, ScaledUlps, Ulps, UlpsEq};
+
+/// A configured equality comparison between two values.
+///
+/// This is implemented by the [`AbsDiff`], [`Relative`] and [`Ulps`] structs, and allows them to
+/// be combined using the [`And`], [`Or`] and [`Not`] combinators. The `approx_{eq|ne}!` and
+/// `assert_approx_{eq|ne}!` macros are built on this trait.
+///
+/// Unlike the `eq` and `ne` methods of the structs, which consume the comparison, `compare`
+/// borrows it so that it can be reused, and so requires the `Epsilon` to be `Clone`.
+///
+/// # Examples
+///
+/// ```rust
+/// use approx::{AbsDiff, Comparator, Ulps};
+///
+/// let within = AbsDiff::default().epsilon(1e-12).or(Ulps::default().max_ulps(4));
+/// assert!(within.compare(&1e-13, &0.0));
+/// assert!(within.compare(&1e10, &(1e10 + 2e-6)));
+/// assert!(!within.compare(&1e-11, &0.0));
+/// ```
+pub trait Comparator<A, B = A>
+where
+    A: ?Sized,
+    B: ?Sized,
+{
+    /// Peform the comparison, returning `true` if the values are considered to be equal.
+    fn compare(&self, lhs: &A, rhs: &B) -> bool;
+
+    /// Combine with another comparison, considering the values to be equal only if both consider
+    /// them to be equal.
+    #[inline]
+    fn and<C>(self, other: C) -> And<Self, C>
+    where
+        Self: Sized,
+        C: Comparator<A, B>,
+    {
+        And(self, other)
+    }
+
+    /// Combine with another comparison, considering the values to be equal if either considers
+    /// them to be equal.
+    #[inline]
+    fn or<C>(self, other: C) -> Or<Self, C>
+    where
+        Self: Sized,
+        C: Comparator<A, B>,
+    {
+        Or(self, other)
+    }
+
+    /// Invert the comparison.
+    #[inline]
+    fn not(self) -> Not<Self>
+    where
+        Self: Sized,
+    {
+        Not(self)
+    }
+}
+
+/// A comparison that considers the values to be equal if both of the inner comparisons do.
+#[derive(Debug, Clone, Copy)]
+pub struct And<C, D>(pub C, pub D);
+
+/// A comparison that considers the values to be equal if either of the inner comparisons do.
+#[derive(Debug, Clone, Copy)]
+pub struct Or<C, D>(pub C, pub D);
+
+/// A comparison that considers the values to be equal if the inner comparison does not.
+#[derive(Debug, Clone, Copy)]
+pub struct Not<C>(pub C);
+
+impl<A, B, C, D> Comparator<A, B> for And<C, D>
+where
+    A: ?Sized,
+    B: ?Sized,
+    C: Comparator<A, B>,
+    D: Comparator<A, B>,
+{
+    #[inline]
+    fn compare(&self, lhs: &A, rhs: &B) -> bool {
+        self.0.compare(lhs, rhs) && self.1.compare(lhs, rhs)
+    }
+}
+
+impl<A, B, C, D> Comparator<A, B> for Or<C, D>
+where
+    A: ?Sized,
+    B: ?Sized,
+    C: Comparator<A, B>,
+    D: Comparator<A, B>,
+{
+    #[inline]
+    fn compare(&self, lhs: &A, rhs: &B) -> bool {
+        self.0.compare(lhs, rhs) || self.1.compare(lhs, rhs)
+    }
+}
+
+impl<A, B, C> Comparator<A, B> for Not<C>
+where
+    A: ?Sized,
+    B: ?Sized,
+    C: Comparator<A, B>,
+{
+    #[inline]
+    fn compare(&self, lhs: &A, rhs: &B) -> bool {
+        !self.0.compare(lhs, rhs)
+    }
+}
+
+impl<A, B, C> Comparator<A, B> for &C
+where
+    A: ?Sized,
+    B: ?Sized,
+    C: Comparator<A, B> + ?Sized,
+{
+    #[inline]
+    fn compare(&self, lhs: &A, rhs: &B) -> bool {
+        C::compare(*self, lhs, rhs)
+    }
+}
+
+impl<A, B> Comparator<A, B> for AbsDiff<A, B>
+where
+    A: AbsDiffEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: Clone,
+{
+    #[inline]
+    fn compare(&self, lhs: &A, rhs: &B) -> bool {
+        A::abs_diff_eq(lhs, rhs, self.epsilon.clone())
+    }
+}
+
+impl<A, B> Comparator<A, B> for Relative<A, B>
+where
+    A: RelativeEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: Clone,
+{
+    #[inline]
+    fn compare(&self, lhs: &A, rhs: &B) -> bool {
+        A::relative_eq(lhs, rhs, self.epsilon.clone(), self.max_relative.clone())
+    }
+}
+
+impl<A, B> Comparator<A, B> for Ulps<A, B>
+where
+    A: UlpsEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: Clone,
+{
+    #[inline]
+    fn compare(&self, lhs: &A, rhs: &B) -> bool {
+        A::ulps_eq(lhs, rhs, self.epsilon.clone(), self.max_ulps)
+    }
+}
+
+impl<A, B> Comparator<A, B> for ScaledRelative<A, B>
+where
+    A: RelativeEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: FloatCore,
+{
+    #[inline]
+    fn compare(&self, lhs: &A, rhs: &B) -> bool {
+        A::abs_diff_eq(lhs, rhs, self.tolerance())
+    }
+}
+
+impl<A, B> Comparator<A, B> for ScaledUlps<A, B>
+where
+    A: UlpsEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: FloatCore,
+{
+    #[inline]
+    fn compare(&self, lhs: &A, rhs: &B) -> bool {
+        A::abs_diff_eq(lhs, rhs, self.tolerance())
+    }
+}
+
+macro_rules! impl_predicates {
+    ($T:ident: $Eq:ident, A::Epsilon: $Bound:ident) => {
+        impl<A, B> $T<A, B>
+        where
+            A: $Eq<B> + ?Sized,
+            B: ?Sized,
+            A::Epsilon: $Bound,
+        {
+            /// Borrow the comparison as a predicate on pairs of values, such as those yielded by
+            /// [`Iterator::zip`].
+            #[inline]
+            pub fn predicate<'a>(&'a self) -> impl Fn((&A, &B)) -> bool + 'a {
+                move |(lhs, rhs)| self.compare(lhs, rhs)
+            }
+
+            /// Borrow the comparison as a predicate on values that are compared against `rhs`,
+            /// such as for [`Iterator::filter`] or `Vec::retain`.
+            #[inline]
+            pub fn eq_to<'a>(&'a self, rhs: &'a B) -> impl Fn(&A) -> bool + 'a {
+                move |lhs| self.compare(lhs, rhs)
+            }
+        }
+    };
+}
+
+impl_predicates!(AbsDiff: AbsDiffEq, A::Epsilon: Clone);
+impl_predicates!(Relative: RelativeEq, A::Epsilon: Clone);
+impl_predicates!(Ulps: UlpsEq, A::Epsilon: Clone);
+impl_predicates!(ScaledRelative: RelativeEq, A::Epsilon: FloatCore);
+impl_predicates!(ScaledUlps: UlpsEq, A::Epsilon: FloatCore);
+
+/// Used by the `approx_{eq|ne}!` macros to disable the absolute tolerance of the relative and
+/// ULPs based comparisons.
+#[doc(hidden)]
+#[inline]
+pub fn __zero<T: num_traits::Zero>() -> T {
+    T::zero()
+}
@@ -0,0 +1,412 @@
+use core::fmt;
+#[cfg(feature = "std")]
+use std::cell::Cell;
+#[cfg(feature = "std")]
+use std::env;
+#[cfg(feature = "std")]
+use std::error::Error;
+#[cfg(feature = "std")]
+use std::string::String;
+use AbsDiffEq;
+
+/// Overrides of the default tolerances, for running the same tests on targets whose floating
+/// point arithmetic is less accurate.
+///
+/// The overrides are consulted by the `default` constructors of the comparison structs, and so by
+/// the macros, for the tolerances that are not given explicitly. They can be replaced for the
+/// duration of a closure using [`with_defaults`], or for a whole test using the `#[approx_test]`
+/// attribute of the `approx-macros` crate.
+///
+/// Outside of these, the overrides are read from the environment, once for each thread:
+///
+/// - `APPROX_TOLERANCE_SCALE`: the factor that the default tolerances are multiplied by
+/// - `APPROX_MAX_ULPS`: the ULPs to tolerate in place of the default
+///
+/// A variable that is not valid is never ignored, so that a mistyped override does not silently
+/// run the tests with the tolerances that it was meant to loosen: the constructors panic with the
+/// [`InvalidEnvVar`] error instead.
+///
+/// The `epsilon` and `max_relative` overrides replace the defaults of the compared types, and are
+/// then multiplied by `scale` like the defaults that they replace.
+///
+/// # Example
+///
+/// ```rust
+/// use approx::{with_defaults, Defaults, Relative};
+///
+/// assert!(Relative::default().ne(&1.0, &(1.0 + 1e-15)));
+/// with_defaults(Defaults { scale: 10.0, ..Defaults::default() }, || {
+///     assert!(Relative::default().eq(&1.0, &(1.0 + 1e-15)));
+/// });
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Defaults {
+    /// The factor that the default tolerances are multiplied by.
+    pub scale: f64,
+    /// The tolerance for values that are close together in place of the default, if any.
+    pub epsilon: Option<f64>,
+    /// The relative tolerance in place of the default, if any.
+    pub max_relative: Option<f64>,
+    /// The ULPs to tolerate in place of the default, if any.
+    pub max_ulps: Option<u32>,
+}
+
+#[cfg(feature = "std")]
+impl Default for Defaults {
+    #[inline]
+    fn default() -> Defaults {
+        Defaults {
+            scale: 1.0,
+            epsilon: None,
+            max_relative: None,
+            max_ulps: None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    // The overrides of the innermost `with_defaults`, if any. A `const` initializer would need a
+    // newer compiler than the minimum supported version.
+    #[allow(clippy::missing_const_for_thread_local)]
+    static CURRENT: Cell<Option<Defaults>> = Cell::new(None);
+
+    // `None` until the overrides are first read from the environment.
+    #[allow(clippy::missing_const_for_thread_local)]
+    static FROM_ENV: Cell<Option<Defaults>> = Cell::new(None);
+
+    // The overrides that were applied since the start of the current assertion.
+    #[allow(clippy::missing_const_for_thread_local)]
+    static APPLIED: Cell<Applied> = Cell::new(Applied::default());
+}
+
+/// Which of the overrides were applied to a default tolerance.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+struct Applied {
+    scale: bool,
+    epsilon: bool,
+    max_relative: bool,
+    max_ulps: bool,
+}
+
+#[cfg(feature = "std")]
+fn applied<F: FnOnce(&mut Applied)>(f: F) {
+    APPLIED.with(|applied| {
+        let mut value = applied.get();
+        f(&mut value);
+        applied.set(value);
+    });
+}
+
+#[cfg(feature = "std")]
+impl Defaults {
+    /// The overrides that are in effect on the current thread.
+    ///
+    /// These are the overrides of the innermost [`with_defaults`], if any, and those read from the
+    /// environment otherwise.
+    ///
+    /// # Panics
+    ///
+    /// If the overrides are read from the environment and a variable is not valid.
+    pub fn current() -> Defaults {
+        match CURRENT.with(Cell::get) {
+            Some(defaults) => defaults,
+            None => env_defaults().unwrap_or_else(|error| panic!("{}", error)),
+        }
+    }
+
+    /// Read the overrides from the `APPROX_TOLERANCE_SCALE` and `APPROX_MAX_ULPS` environment
+    /// variables.
+    ///
+    /// # Errors
+    ///
+    /// If either variable is set to a value that is not a finite, non-negative number.
+    pub fn from_env() -> Result<Defaults, InvalidEnvVar> {
+        let mut defaults = Defaults::default();
+        read_env(&mut defaults).map(|()| defaults)
+    }
+}
+
+/// The overrides read from the environment, which are only read again while they are invalid.
+#[cfg(feature = "std")]
+fn env_defaults() -> Result<Defaults, InvalidEnvVar> {
+    if let Some(defaults) = FROM_ENV.with(Cell::get) {
+        return Ok(defaults);
+    }
+    let defaults = Defaults::from_env()?;
+    FROM_ENV.with(|from_env| from_env.set(Some(defaults)));
+    Ok(defaults)
+}
+
+/// Read the overrides from the environment into `defaults`, keeping the variables that are valid
+/// and returning the first that is not.
+#[cfg(feature = "std")]
+fn read_env(defaults: &mut Defaults) -> Result<(), InvalidEnvVar> {
+    let mut result = Ok(());
+    if let Ok(value) = env::var("APPROX_TOLERANCE_SCALE") {
+        match value.trim().parse::<f64>() {
+            Ok(scale) if scale >= 0.0 && scale.is_finite() => defaults.scale = scale,
+            _ => {
+                result = result.and(Err(InvalidEnvVar {
+                    name: "APPROX_TOLERANCE_SCALE",
+                    value,
+                }))
+            }
+        }
+    }
+    if let Ok(value) = env::var("APPROX_MAX_ULPS") {
+        match value.trim().parse::<u32>() {
+            Ok(max_ulps) => defaults.max_ulps = Some(max_ulps),
+            Err(_) => {
+                result = result.and(Err(InvalidEnvVar {
+                    name: "APPROX_MAX_ULPS",
+                    value,
+                }))
+            }
+        }
+    }
+    result
+}
+
+/// An environment variable whose value is not a valid override, as returned by
+/// [`Defaults::from_env`], and with which [`Defaults::current`] panics.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidEnvVar {
+    name: &'static str,
+    value: String,
+}
+
+#[cfg(feature = "std")]
+impl InvalidEnvVar {
+    /// The name of the variable.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The value of the variable.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for InvalidEnvVar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let expected = match self.name {
+            "APPROX_MAX_ULPS" => "a non-negative integer",
+            _ => "a non-negative number",
+        };
+        write!(
+            f,
+            "invalid {} `{}`: expected {}",
+            self.name, self.value, expected
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for InvalidEnvVar {}
+
+/// Formats the overrides as they appear in the failure messages of the assertion macros, such as
+/// `scale = 10, max_relative = 1e-6, max_ulps = 8`.
+#[cfg(feature = "std")]
+impl fmt::Display for Defaults {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "scale = {}", self.scale)?;
+        if let Some(epsilon) = self.epsilon {
+            write!(f, ", epsilon = {:e}", epsilon)?;
+        }
+        if let Some(max_relative) = self.max_relative {
+            write!(f, ", max_relative = {:e}", max_relative)?;
+        }
+        if let Some(max_ulps) = self.max_ulps {
+            write!(f, ", max_ulps = {}", max_ulps)?;
+        }
+        Ok(())
+    }
+}
+
+/// Run a closure with the default tolerances of the current thread overridden.
+///
+/// The overrides replace those read from the environment, and the previous overrides are
+/// restored when the closure returns or panics. To adjust the overrides that are in effect
+/// instead, start from [`Defaults::current`].
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+///
+/// use approx::{with_defaults, Defaults};
+///
+/// # fn main() {
+/// with_defaults(Defaults { max_ulps: Some(8), ..Defaults::current() }, || {
+///     assert_ulps_eq!(1.0, 1.0 + 6.0 * std::f64::EPSILON);
+/// });
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub fn with_defaults<F, R>(defaults: Defaults, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    struct Restore(Option<Defaults>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT.with(|current| current.set(self.0));
+        }
+    }
+
+    let _restore = Restore(CURRENT.with(|current| current.replace(Some(defaults))));
+    f()
+}
+
+/// Used by the assertion macros to track which of the overrides are applied to a comparison.
+#[doc(hidden)]
+pub fn __assert_defaults<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    #[cfg(feature = "std")]
+    APPLIED.with(|applied| applied.set(Applied::default()));
+    f()
+}
+
+/// Apply the overrides to a default `epsilon`.
+#[inline]
+pub(crate) fn epsilon<A, B>(epsilon: A::Epsilon) -> A::Epsilon
+where
+    A: AbsDiffEq<B> + ?Sized,
+    B: ?Sized,
+{
+    #[cfg(feature = "std")]
+    let epsilon = {
+        let defaults = Defaults::current();
+        let epsilon = match replace::<A, B>(defaults.epsilon) {
+            Some(epsilon) => {
+                applied(|applied| applied.epsilon = true);
+                epsilon
+            }
+            None => epsilon,
+        };
+        scale::<A, B>(epsilon, defaults.scale)
+    };
+    epsilon
+}
+
+/// Apply the overrides to a default `max_relative`.
+#[inline]
+pub(crate) fn max_relative<A, B>(max_relative: A::Epsilon) -> A::Epsilon
+where
+    A: AbsDiffEq<B> + ?Sized,
+    B: ?Sized,
+{
+    #[cfg(feature = "std")]
+    let max_relative = {
+        let defaults = Defaults::current();
+        let max_relative = match replace::<A, B>(defaults.max_relative) {
+            Some(max_relative) => {
+                applied(|applied| applied.max_relative = true);
+                max_relative
+            }
+            None => max_relative,
+        };
+        scale::<A, B>(max_relative, defaults.scale)
+    };
+    max_relative
+}
+
+/// Apply the scale override to a default tolerance that cannot otherwise be overridden.
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn scaled<A, B>(tolerance: A::Epsilon) -> A::Epsilon
+where
+    A: AbsDiffEq<B> + ?Sized,
+    B: ?Sized,
+{
+    scale::<A, B>(tolerance, Defaults::current().scale)
+}
+
+#[cfg(feature = "std")]
+fn replace<A, B>(value: Option<f64>) -> Option<A::Epsilon>
+where
+    A: AbsDiffEq<B> + ?Sized,
+    B: ?Sized,
+{
+    value.and_then(A::epsilon_from_f64)
+}
+
+#[cfg(feature = "std")]
+fn scale<A, B>(tolerance: A::Epsilon, scale: f64) -> A::Epsilon
+where
+    A: AbsDiffEq<B> + ?Sized,
+    B: ?Sized,
+{
+    if scale == 1.0 {
+        return tolerance;
+    }
+    match A::scale_epsilon(&tolerance, scale) {
+        Some(tolerance) => {
+            applied(|applied| applied.scale = true);
+            tolerance
+        }
+        None => tolerance,
+    }
+}
+
+/// Apply the overrides to a default ULPs value.
+#[inline]
+pub(crate) fn max_ulps(max_ulps: u32) -> u32 {
+    #[cfg(feature = "std")]
+    let max_ulps = match Defaults::current().max_ulps {
+        Some(max_ulps) => {
+            applied(|applied| applied.max_ulps = true);
+            max_ulps
+        }
+        None => max_ulps,
+    };
+    max_ulps
+}
+
+/// Used by the assertion macros to show the overrides that were applied to the comparison, if
+/// any, in the same format as [`Defaults`].
+#[doc(hidden)]
+pub struct __DefaultsNote;
+
+impl fmt::Display for __DefaultsNote {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (defaults, applied) = (Defaults::current(), APPLIED.with(Cell::get));
+        let mut prefix = "    defaults: ";
+        if applied.scale {
+            write!(f, "{}scale = {}", prefix, defaults.scale)?;
+            prefix = ", ";
+        }
+        if let (true, Some(epsilon)) = (applied.epsilon, defaults.epsilon) {
+            write!(f, "{}epsilon = {:e}", prefix, epsilon)?;
+            prefix = ", ";
+        }
+        if let (true, Some(max_relative)) = (applied.max_relative, defaults.max_relative) {
+            write!(f, "{}max_relative = {:e}", prefix, max_relative)?;
+            prefix = ", ";
+        }
+        if let (true, Some(max_ulps)) = (applied.max_ulps, defaults.max_ulps) {
+            write!(f, "{}max_ulps = {}", prefix, max_ulps)?;
+            prefix = ", ";
+        }
+        if prefix == ", " {
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use {Relative, RelativeEq};
+
+/// Used by the `approx_or_{eq|ne}!` macros to compare values using [`RelativeEq`] when they
+/// implement it, and using `PartialEq` otherwise.
+///
+/// This relies on method resolution preferring [`__ApproxOrRelative`], which takes
+/// `&__ApproxOr`, over [`__ApproxOrPartialEq`], which takes `&&__ApproxOr`. As method resolution
+/// happens where the macros are expanded, values of a generic type are only compared using
+/// `RelativeEq` if it is required by the bounds of the type.
+#[doc(hidden)]
+pub struct __ApproxOr<'a, A: 'a + ?Sized, B: 'a + ?Sized>(pub &'a A, pub &'a B);
+
+#[doc(hidden)]
+pub trait __ApproxOrRelative {
+    fn approx_or_eq(&self) -> bool;
+}
+
+impl<'a, A, B> __ApproxOrRelative for __ApproxOr<'a, A, B>
+where
+    A: RelativeEq<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn approx_or_eq(&self) -> bool {
+        Relative::default().eq(self.0, self.1)
+    }
+}
+
+#[doc(hidden)]
+pub trait __ApproxOrPartialEq {
+    fn approx_or_eq(&self) -> bool;
+}
+
+impl<'a, 'b, A, B> __ApproxOrPartialEq for &'b __ApproxOr<'a, A, B>
+where
+    A: PartialEq<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn approx_or_eq(&self) -> bool {
+        self.0 == self.1
+    }
+}
@@ -0,0 +1,93 @@
+use core::any::Any;
+use {RelativeEq, Tolerance, UlpsEq};
+
+/// Approximate equality of trait objects, for comparing values whose types are only known at
+/// runtime.
+///
+/// The approximate equality traits cannot be used as trait objects, so this is implemented for
+/// every type that implements [`RelativeEq`] and [`UlpsEq`] with an `Epsilon` that implements
+/// [`DynEpsilon`], and compares values using a [`Tolerance`] whose epsilons are converted from
+/// `f64` to that `Epsilon`. The types must also implement `PartialEq`, which is used by
+/// [`Tolerance::Exact`], as the tolerance is only known at runtime. Values of different types
+/// are never equal.
+///
+/// # Example
+///
+/// ```rust
+/// use approx::{DynApproxEq, Tolerance};
+///
+/// let results: Vec<Box<dyn DynApproxEq>> = vec![Box::new(1.0f64), Box::new(2.0f32), Box::new(true)];
+/// let expected: Vec<Box<dyn DynApproxEq>> = vec![Box::new(1.05f64), Box::new(2.0f32), Box::new(true)];
+///
+/// let tolerance = Tolerance::Abs { epsilon: 0.1 };
+/// for (result, expected) in results.iter().zip(&expected) {
+///     assert!(result.dyn_approx_eq(&**expected, &tolerance));
+/// }
+/// assert!(results[0].dyn_approx_ne(&*results[1], &tolerance));
+/// ```
+pub trait DynApproxEq: Any {
+    /// The value as `Any`, so that it can be downcast.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Peform the equality comparison
+    fn dyn_approx_eq(&self, other: &dyn DynApproxEq, tolerance: &Tolerance<f64>) -> bool;
+
+    /// Peform the inequality comparison
+    fn dyn_approx_ne(&self, other: &dyn DynApproxEq, tolerance: &Tolerance<f64>) -> bool {
+        !self.dyn_approx_eq(other, tolerance)
+    }
+}
+
+impl<T> DynApproxEq for T
+where
+    T: RelativeEq + UlpsEq + PartialEq + Any,
+    T::Epsilon: DynEpsilon + Clone,
+{
+    #[inline]
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_approx_eq(&self, other: &dyn DynApproxEq, tolerance: &Tolerance<f64>) -> bool {
+        let other = match other.as_any().downcast_ref::<T>() {
+            Some(other) => other,
+            None => return false,
+        };
+        match tolerance.try_map(&|&epsilon| T::Epsilon::from_f64(epsilon)) {
+            Some(tolerance) => tolerance.eq(self, other),
+            None => false,
+        }
+    }
+}
+
+/// An `Epsilon` that the `f64` tolerances used by [`DynApproxEq`] can be converted to.
+///
+/// This is implemented for the primitive numeric types, and for `()`, the `Epsilon` of the types
+/// that are compared exactly, which ignores the tolerance.
+pub trait DynEpsilon: Sized {
+    /// Convert a tolerance, or return `None` if the type cannot represent it, such as a NaN
+    /// tolerance for an integer type. Values are never equal under such a tolerance.
+    fn from_f64(value: f64) -> Option<Self>;
+}
+
+macro_rules! impl_dyn_epsilon {
+    ($($T:ident),+) => {
+        $(
+            impl DynEpsilon for $T {
+                #[inline]
+                fn from_f64(value: f64) -> Option<$T> {
+                    num_traits::cast(value)
+                }
+            }
+        )+
+    };
+}
+
+impl_dyn_epsilon!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl DynEpsilon for () {
+    #[inline]
+    fn from_f64(_: f64) -> Option<()> {
+        Some(())
+    }
+}
@@ -0,0 +1,101 @@
+use core::fmt;
+use {AbsDiffEq, RelativeEq, UlpsEq};
+
+/// A value that is compared exactly using `PartialEq`, ignoring the tolerances.
+///
+/// This allows types without approximate equality, such as fieldless enums, to be used alongside
+/// numbers in the implementations for composite types, and in generic code that requires the
+/// approximate equality traits. Like the implementations for `bool`, `char`, `str` and `String`,
+/// its `Epsilon` is `()`.
+///
+/// The `Debug` output is that of the wrapped value.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+///
+/// use approx::{AbsDiffEq, Exact};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Unit {
+///     Meters,
+///     Feet,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Length {
+///     value: f64,
+///     unit: Exact<Unit>,
+/// }
+///
+/// impl AbsDiffEq for Length {
+///     type Epsilon = f64;
+///
+///     fn default_epsilon() -> f64 {
+///         f64::default_epsilon()
+///     }
+///
+///     fn abs_diff_eq(&self, other: &Length, epsilon: f64) -> bool {
+///         f64::abs_diff_eq(&self.value, &other.value, epsilon)
+///             && self.unit.abs_diff_eq(&other.unit, ())
+///     }
+/// }
+///
+/// # fn main() {
+/// let meters = Length { value: 1.0, unit: Exact(Unit::Meters) };
+/// let feet = Length { value: 1.0, unit: Exact(Unit::Feet) };
+///
+/// assert_abs_diff_eq!(meters, Length { value: 1.05, ..meters }, epsilon = 0.1);
+/// assert_abs_diff_ne!(meters, feet, epsilon = 0.1);
+/// assert_relative_eq!(Exact(Unit::Feet), Exact(Unit::Feet));
+/// # }
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Exact<T>(pub T);
+
+impl<T: fmt::Debug> fmt::Debug for Exact<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: PartialEq> AbsDiffEq for Exact<T> {
+    type Epsilon = ();
+
+    #[inline]
+    fn default_epsilon() {}
+
+    #[inline]
+    fn epsilon_from_f64(_: f64) -> Option<()> {
+        Some(())
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Exact<T>, _: ()) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: PartialEq> RelativeEq for Exact<T> {
+    #[inline]
+    fn default_max_relative() {}
+
+    #[inline]
+    fn relative_eq(&self, other: &Exact<T>, _: (), _: ()) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: PartialEq> UlpsEq for Exact<T> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        0
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Exact<T>, _: (), _: u32) -> bool {
+        self.0 == other.0
+    }
+}
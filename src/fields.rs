@@ -0,0 +1,294 @@
+use core::fmt;
+use std::string::String;
+use std::vec::Vec;
+use {AbsDiffEq, Relative, RelativeEq, Tolerance, UlpsEq};
+
+/// A type whose approximate equality is that of each of its fields, which can report the fields
+/// that are not equal.
+///
+/// The fields are visited in pairs, using [`Fields::field`] for the numeric fields and
+/// [`Fields::nested`] for fields that implement `ApproxFields` themselves, so that mismatches are
+/// reported with paths such as `pose.translation.x`.
+///
+/// Wrapping values in [`ByFields`] implements [`AbsDiffEq`], [`RelativeEq`] and [`UlpsEq`] in
+/// terms of the fields, and the `assert_fields_{eq|ne}!` macros list the mismatched fields when
+/// they fail.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+///
+/// use approx::{ApproxFields, Fields, Relative};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Vector {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Pose {
+///     translation: Vector,
+///     angle: f64,
+/// }
+///
+/// impl ApproxFields for Vector {
+///     type Scalar = f64;
+///
+///     fn visit_fields(&self, other: &Vector, fields: &mut Fields<f64>) {
+///         fields.field("x", &self.x, &other.x);
+///         fields.field("y", &self.y, &other.y);
+///     }
+/// }
+///
+/// impl ApproxFields for Pose {
+///     type Scalar = f64;
+///
+///     fn visit_fields(&self, other: &Pose, fields: &mut Fields<f64>) {
+///         fields.nested("translation", &self.translation, &other.translation);
+///         fields.field("angle", &self.angle, &other.angle);
+///     }
+/// }
+///
+/// # fn main() {
+/// let pose = Pose { translation: Vector { x: 1.0, y: 2.0 }, angle: 0.5 };
+/// let moved = Pose { translation: Vector { x: 1.5, y: 2.0 }, angle: 0.5 };
+///
+/// assert_fields_eq!(pose, moved, epsilon = 0.5);
+///
+/// let mismatches = approx::field_mismatches(&pose, &moved, &Relative::<f64>::default().into());
+/// assert_eq!(mismatches.len(), 1);
+/// assert_eq!(mismatches[0].to_string(), "translation.x: left = 1.0, right = 1.5");
+/// # }
+/// ```
+pub trait ApproxFields {
+    /// The type whose tolerances are used to compare the fields, such as `f64`.
+    type Scalar: RelativeEq + UlpsEq;
+
+    /// Visit each pair of fields of `self` and `other`.
+    fn visit_fields(&self, other: &Self, fields: &mut Fields<<Self::Scalar as AbsDiffEq>::Epsilon>);
+}
+
+/// Compares the pairs of fields that are visited by [`ApproxFields::visit_fields`].
+pub struct Fields<'a, E: 'a> {
+    tolerance: &'a Tolerance<E>,
+    path: String,
+    report: bool,
+    mismatches: Vec<FieldMismatch>,
+}
+
+impl<'a, E: Clone> Fields<'a, E> {
+    fn new(tolerance: &'a Tolerance<E>, report: bool) -> Fields<'a, E> {
+        Fields {
+            tolerance,
+            path: String::new(),
+            report,
+            mismatches: Vec::new(),
+        }
+    }
+
+    /// Whether the fields can stop being compared, because a mismatch has been found and the
+    /// mismatches are not being reported.
+    fn done(&self) -> bool {
+        !self.report && !self.mismatches.is_empty()
+    }
+
+    fn push(&mut self, name: &str) -> usize {
+        let len = self.path.len();
+        if len > 0 {
+            self.path.push('.');
+        }
+        self.path.push_str(name);
+        len
+    }
+
+    /// Compare a pair of numeric fields.
+    pub fn field<T>(&mut self, name: &str, lhs: &T, rhs: &T)
+    where
+        T: RelativeEq<Epsilon = E> + UlpsEq + PartialEq + fmt::Debug + ?Sized,
+    {
+        if self.done() || self.tolerance.eq(lhs, rhs) {
+            return;
+        }
+
+        let len = self.push(name);
+        self.mismatches.push(if self.report {
+            FieldMismatch {
+                path: self.path.clone(),
+                left: std::format!("{:?}", lhs),
+                right: std::format!("{:?}", rhs),
+            }
+        } else {
+            FieldMismatch {
+                path: String::new(),
+                left: String::new(),
+                right: String::new(),
+            }
+        });
+        self.path.truncate(len);
+    }
+
+    /// Compare a pair of fields that implement [`ApproxFields`] themselves.
+    pub fn nested<T>(&mut self, name: &str, lhs: &T, rhs: &T)
+    where
+        T: ApproxFields + ?Sized,
+        T::Scalar: AbsDiffEq<Epsilon = E>,
+    {
+        if self.done() {
+            return;
+        }
+
+        let len = self.push(name);
+        lhs.visit_fields(rhs, self);
+        self.path.truncate(len);
+    }
+}
+
+/// A field that was not approximately equal, as returned by [`field_mismatches`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldMismatch {
+    /// The path of the field, such as `pose.translation.x`.
+    pub path: String,
+    /// The `Debug` representation of the field of the left value.
+    pub left: String,
+    /// The `Debug` representation of the field of the right value.
+    pub right: String,
+}
+
+/// Formats the mismatch as `path: left = .., right = ..`.
+impl fmt::Display for FieldMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: left = {}, right = {}",
+            self.path, self.left, self.right
+        )
+    }
+}
+
+/// Compare each of the fields of two values, returning those that are not equal under the
+/// tolerance.
+pub fn field_mismatches<T>(
+    lhs: &T,
+    rhs: &T,
+    tolerance: &Tolerance<<T::Scalar as AbsDiffEq>::Epsilon>,
+) -> Vec<FieldMismatch>
+where
+    T: ApproxFields + ?Sized,
+    <T::Scalar as AbsDiffEq>::Epsilon: Clone,
+{
+    let mut fields = Fields::new(tolerance, true);
+    lhs.visit_fields(rhs, &mut fields);
+    fields.mismatches
+}
+
+fn fields_eq<T>(lhs: &T, rhs: &T, tolerance: &Tolerance<<T::Scalar as AbsDiffEq>::Epsilon>) -> bool
+where
+    T: ApproxFields + ?Sized,
+    <T::Scalar as AbsDiffEq>::Epsilon: Clone,
+{
+    let mut fields = Fields::new(tolerance, false);
+    lhs.visit_fields(rhs, &mut fields);
+    fields.mismatches.is_empty()
+}
+
+/// A value that implements the approximate equality traits by comparing each of its fields, as
+/// visited by [`ApproxFields`].
+///
+/// The `Debug` output is that of the wrapped value.
+#[derive(Clone, Copy, PartialEq)]
+pub struct ByFields<T>(pub T);
+
+impl<T: fmt::Debug> fmt::Debug for ByFields<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T> AbsDiffEq for ByFields<T>
+where
+    T: ApproxFields,
+    <T::Scalar as AbsDiffEq>::Epsilon: Clone,
+{
+    type Epsilon = <T::Scalar as AbsDiffEq>::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::Scalar::default_epsilon()
+    }
+
+    #[inline]
+    fn scale_epsilon(epsilon: &Self::Epsilon, scale: f64) -> Option<Self::Epsilon> {
+        T::Scalar::scale_epsilon(epsilon, scale)
+    }
+
+    #[inline]
+    fn epsilon_from_f64(value: f64) -> Option<Self::Epsilon> {
+        T::Scalar::epsilon_from_f64(value)
+    }
+
+    fn abs_diff_eq(&self, other: &ByFields<T>, epsilon: Self::Epsilon) -> bool {
+        fields_eq(&self.0, &other.0, &Tolerance::Abs { epsilon })
+    }
+}
+
+impl<T> RelativeEq for ByFields<T>
+where
+    T: ApproxFields,
+    <T::Scalar as AbsDiffEq>::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::Scalar::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &ByFields<T>,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        let tolerance = Tolerance::Relative {
+            epsilon,
+            max_relative,
+        };
+        fields_eq(&self.0, &other.0, &tolerance)
+    }
+}
+
+impl<T> UlpsEq for ByFields<T>
+where
+    T: ApproxFields,
+    <T::Scalar as AbsDiffEq>::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::Scalar::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &ByFields<T>, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        fields_eq(&self.0, &other.0, &Tolerance::Ulps { epsilon, max_ulps })
+    }
+}
+
+/// Used by the `assert_fields_{eq|ne}!` macros to name the type of the relative comparison.
+#[doc(hidden)]
+#[inline]
+pub fn __fields_relative<T: ApproxFields + ?Sized>(_: &T) -> Relative<T::Scalar> {
+    Relative::default()
+}
+
+/// Used by the `assert_fields_eq!` macro to list the mismatched fields.
+#[doc(hidden)]
+pub struct __FieldReport<'a>(pub &'a [FieldMismatch]);
+
+impl<'a> fmt::Display for __FieldReport<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for mismatch in self.0 {
+            writeln!(f, "        {}", mismatch)?;
+        }
+        Ok(())
+    }
+}
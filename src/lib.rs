@@ -40,6 +40,30 @@
 //! ulps_eq!(1.0, 1.0, max_ulps = 4);
 //! ulps_eq!(1.0, 1.0, epsilon = f64::EPSILON, max_ulps = 4);
 //! ulps_eq!(1.0, 1.0, max_ulps = 4, epsilon = f64::EPSILON);
+//!
+//! relative_eq!(1e-6, 0.0, scale = 1e10);
+//! ulps_eq!(1e-6, 0.0, scale = 1e10, max_ulps = 1000);
+//! approx_zero!(1e-6, scale = 1e10);
+//!
+//! # #[cfg(feature = "std")] {
+//! log_relative_eq!(1e-300, 1e-300);
+//! log_relative_eq!(1e-300, 1e-300, max_log_diff = 1e-6);
+//! log_relative_eq!(1e-300, 1e-300, max_decibels = 0.1);
+//! # }
+//!
+//! angle_eq!(f64::consts::PI, -f64::consts::PI);
+//! angle_eq!(359.0, -1.0, period = 360.0);
+//!
+//! # #[cfg(feature = "std")] {
+//! sig_figs_eq!(1234.5, 1234.9, sig_figs = 4);
+//! places_eq!(1.23456, 1.23462, places = 3);
+//! # }
+//!
+//! approx_eq!(1.0, 1.0);
+//! approx_eq!(1.0, 1.0, abs = 1e-12, ulps = 4);
+//! approx_eq!(1.0, 1.0, rel = 1e-6, abs = 1e-3, mode = all);
+//!
+//! bit_eq!(f64::NAN, f64::NAN);
 //! # }
 //! ```
 //!
@@ -143,6 +167,44 @@
 //! }
 //! ```
 //!
+//! Implementations like these, which compare each field in turn, can also be generated with the
+//! `impl_approx!` macro, such as `impl_approx!(Complex<T> { x, i })`.
+//! Types that implement `ApproxFields` instead can be compared through the `ByFields` wrapper,
+//! and `assert_fields_eq!` reports the path of each field that differs, such as
+//! `pose.translation.x`.
+//!
+//! The traits do not require `PartialEq`, so they can also be implemented for types without
+//! exact equality. Generic code that relied on `T: AbsDiffEq` to compare values with `==`, such
+//! as by using `Tolerance::Exact`, should now require `T: PartialEq` as well. Existing
+//! implementations are unaffected.
+//!
+//! # Tolerance specs
+//!
+//! The `AbsDiff`, `Relative` and `Ulps` structs can be parsed from and formatted as strings,
+//! such as `rel=1e-9,abs=1e-12` or `ulps=4`, for passing tolerances through command line flags,
+//! environment variables or configuration files.
+//!
+//! A spec is a comma separated list of `key=value` items, with optional whitespace around the
+//! keys and values. The keys are:
+//!
+//! - `abs`: the `epsilon` of any of the structs
+//! - `rel`: the `max_relative` of `Relative`
+//! - `ulps`: the `max_ulps` of `Ulps`
+//!
+//! Each key may appear at most once, in any order, and missing keys take their default values,
+//! so the empty spec is the default comparison. Specs are formatted with every key in the order
+//! above, using the `{:e}` format of the values, so that they parse back into the same
+//! comparison.
+//!
+//! ```rust
+//! use approx::Relative;
+//!
+//! let relative: Relative<f64> = "rel=1e-9, abs=1e-12".parse().unwrap();
+//! assert_eq!(relative, Relative::default().epsilon(1e-12).max_relative(1e-9));
+//! assert_eq!(relative.to_string(), "abs=1e-12,rel=1e-9");
+//! assert_eq!(relative.to_string().parse(), Ok(relative));
+//! ```
+//!
 //! # References
 //!
 //! Floating point is hard! Thanks goes to these links for helping to make things a _little_
@@ -155,23 +217,92 @@
 //!   https://docs.oracle.com/cd/E19957-01/806-3568/ncg_goldberg.html)
 
 #![no_std]
-#![allow(clippy::transmute_float_to_int)]
 
+#[cfg(feature = "macros")]
+extern crate approx_macros;
 #[cfg(feature = "num-complex")]
 extern crate num_complex;
 extern crate num_traits;
 #[cfg(feature = "ordered-float")]
 extern crate ordered_float;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 mod abs_diff_eq;
+mod approx;
+mod approx_eq;
+mod bit_eq;
+mod comparator;
+mod defaults;
+mod dispatch;
+mod dyn_eq;
+mod exact;
+#[cfg(feature = "std")]
+mod fields;
+#[cfg(feature = "std")]
+mod log_relative_eq;
+mod mixed;
+mod parse;
+mod periodic_eq;
+#[cfg(feature = "std")]
+mod precision;
+mod profile;
 mod relative_eq;
+mod scaled;
+#[cfg(all(feature = "serde", feature = "std"))]
+mod serde_eq;
+mod tolerance;
 mod ulps_eq;
+mod up_to;
+mod validate;
 
 mod macros;
 
 pub use abs_diff_eq::AbsDiffEq;
+pub use approx::Approx;
+pub use approx_eq::ApproxEq;
+#[cfg(feature = "macros")]
+pub use approx_macros::{approx_test, define_approx_macros};
+pub use bit_eq::{BitEq, Bits};
+#[doc(hidden)]
+pub use comparator::__zero;
+pub use comparator::{And, Comparator, Not, Or};
+#[doc(hidden)]
+pub use defaults::{__DefaultsNote, __assert_defaults};
+#[cfg(feature = "std")]
+pub use defaults::{with_defaults, Defaults, InvalidEnvVar};
+#[doc(hidden)]
+pub use dispatch::{__ApproxOr, __ApproxOrPartialEq, __ApproxOrRelative};
+pub use dyn_eq::{DynApproxEq, DynEpsilon};
+pub use exact::Exact;
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use fields::{__FieldReport, __fields_relative};
+#[cfg(feature = "std")]
+pub use fields::{field_mismatches, ApproxFields, ByFields, FieldMismatch, Fields};
+#[cfg(feature = "std")]
+pub use log_relative_eq::LogRelativeEq;
+pub use mixed::Mixed;
+pub use parse::{ParseToleranceError, ParseToleranceErrorKind};
+pub use periodic_eq::PeriodicEq;
+#[cfg(feature = "std")]
+pub use precision::{__places_tolerance, DecimalPlaces, SigFigs};
+pub use profile::{ToleranceProfile, WithProfile};
 pub use relative_eq::RelativeEq;
+pub use scaled::{ScaledRelative, ScaledUlps};
+#[cfg(all(feature = "serde", feature = "std"))]
+pub use serde_eq::{serde_approx_eq, SerializeError};
+pub use tolerance::Tolerance;
 pub use ulps_eq::UlpsEq;
+#[doc(hidden)]
+pub use validate::{__Check, __CheckFloat, __CheckOther};
+pub use validate::{Epsilon, InvalidToleranceError, InvalidToleranceErrorKind, MaxRelative};
 
 /// The requisite parameters for testing for approximate equality using a
 /// absolute difference based comparison.
@@ -188,6 +319,14 @@ pub use ulps_eq::UlpsEq;
 /// AbsDiff::default().eq(&1.0, &1.0);
 /// AbsDiff::default().epsilon(f64::EPSILON).eq(&1.0, &1.0);
 /// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "A::Epsilon: Serialize",
+        deserialize = "A::Epsilon: Deserialize<'de>"
+    ))
+)]
 pub struct AbsDiff<A, B = A>
 where
     A: AbsDiffEq<B> + ?Sized,
@@ -205,7 +344,7 @@ where
     #[inline]
     fn default() -> AbsDiff<A, B> {
         AbsDiff {
-            epsilon: A::default_epsilon(),
+            epsilon: defaults::epsilon::<A, B>(A::default_epsilon()),
         }
     }
 }
@@ -254,6 +393,14 @@ where
 /// Relative::default().epsilon(f64::EPSILON).max_relative(1.0).eq(&1.0, &1.0);
 /// Relative::default().max_relative(1.0).epsilon(f64::EPSILON).eq(&1.0, &1.0);
 /// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "A::Epsilon: Serialize",
+        deserialize = "A::Epsilon: Deserialize<'de>"
+    ))
+)]
 pub struct Relative<A, B = A>
 where
     A: RelativeEq<B> + ?Sized,
@@ -273,8 +420,8 @@ where
     #[inline]
     fn default() -> Relative<A, B> {
         Relative {
-            epsilon: A::default_epsilon(),
-            max_relative: A::default_max_relative(),
+            epsilon: defaults::epsilon::<A, B>(A::default_epsilon()),
+            max_relative: defaults::max_relative::<A, B>(A::default_max_relative()),
         }
     }
 }
@@ -332,6 +479,14 @@ where
 /// Ulps::default().epsilon(f64::EPSILON).max_ulps(4).eq(&1.0, &1.0);
 /// Ulps::default().max_ulps(4).epsilon(f64::EPSILON).eq(&1.0, &1.0);
 /// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "A::Epsilon: Serialize",
+        deserialize = "A::Epsilon: Deserialize<'de>"
+    ))
+)]
 pub struct Ulps<A, B = A>
 where
     A: UlpsEq<B> + ?Sized,
@@ -351,8 +506,8 @@ where
     #[inline]
     fn default() -> Ulps<A, B> {
         Ulps {
-            epsilon: A::default_epsilon(),
-            max_ulps: A::default_max_ulps(),
+            epsilon: defaults::epsilon::<A, B>(A::default_epsilon()),
+            max_ulps: defaults::max_ulps(A::default_max_ulps()),
         }
     }
 }
@@ -388,3 +543,286 @@ where
         A::ulps_ne(lhs, rhs, self.epsilon, self.max_ulps)
     }
 }
+
+/// The requisite parameters for testing for approximate equality using the difference of the
+/// logarithms of the values.
+///
+/// This is not normally used directly, rather via the `assert_log_relative_{eq|ne}!` and
+/// `log_relative_{eq|ne}!` macros.
+///
+/// # Example
+///
+/// ```rust
+/// use approx::LogRelative;
+///
+/// LogRelative::default().eq(&1.0, &1.0);
+/// LogRelative::default().max_log_diff(1e-3).eq(&1e-300, &1.0001e-300);
+/// LogRelative::default().max_decibels(0.01).eq(&1e10, &1.0001e10);
+/// ```
+#[cfg(feature = "std")]
+pub struct LogRelative<A, B = A>
+where
+    A: LogRelativeEq<B> + ?Sized,
+    B: ?Sized,
+{
+    /// The tolerance for the difference of the natural logarithms of the values.
+    pub max_log_diff: A::Epsilon,
+}
+
+#[cfg(feature = "std")]
+impl<A, B> Default for LogRelative<A, B>
+where
+    A: LogRelativeEq<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn default() -> LogRelative<A, B> {
+        LogRelative {
+            max_log_diff: defaults::scaled::<A, B>(A::default_max_log_diff()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B> LogRelative<A, B>
+where
+    A: LogRelativeEq<B> + ?Sized,
+    B: ?Sized,
+{
+    /// Replace the maximum difference of the natural logarithms with the one specified.
+    #[inline]
+    pub fn max_log_diff(self, max_log_diff: A::Epsilon) -> LogRelative<A, B> {
+        LogRelative { max_log_diff }
+    }
+
+    /// Replace the maximum difference of the natural logarithms with one corresponding to the
+    /// power ratio specified in decibels, `10 log10(a / b)`.
+    #[inline]
+    pub fn max_decibels(self, max_decibels: A::Epsilon) -> LogRelative<A, B>
+    where
+        A::Epsilon: num_traits::Float,
+    {
+        let ten: A::Epsilon = num_traits::cast(10).unwrap();
+        LogRelative {
+            max_log_diff: max_decibels * num_traits::Float::ln(ten) / ten,
+        }
+    }
+
+    /// Peform the equality comparison
+    #[inline]
+    #[must_use]
+    pub fn eq(self, lhs: &A, rhs: &B) -> bool {
+        A::log_relative_eq(lhs, rhs, self.max_log_diff)
+    }
+
+    /// Peform the inequality comparison
+    #[inline]
+    #[must_use]
+    pub fn ne(self, lhs: &A, rhs: &B) -> bool {
+        A::log_relative_ne(lhs, rhs, self.max_log_diff)
+    }
+}
+
+/// The requisite parameters for testing for approximate equality of periodic quantities, such as
+/// angles, using the shortest distance around the period.
+///
+/// By default the values are compared using their absolute difference. Supplying a
+/// `max_relative` or `max_ulps` value also accepts values that are equal under the respective
+/// relative or ULPs based comparison.
+///
+/// This is not normally used directly, rather via the `assert_angle_{eq|ne}!` and
+/// `angle_{eq|ne}!` macros.
+///
+/// # Example
+///
+/// ```rust
+/// use std::f64;
+/// use approx::Periodic;
+///
+/// Periodic::default().eq(&f64::consts::PI, &-f64::consts::PI);
+/// Periodic::radians().epsilon(1e-12).eq(&0.0, &(2.0 * f64::consts::PI));
+/// Periodic::degrees().eq(&359.0, &-1.0);
+/// Periodic::default().period(1.0).max_ulps(4).eq(&0.25, &1.25);
+/// Periodic::default().max_relative(1e-9).eq(&1.0, &(1.0 + 4.0 * f64::consts::PI));
+/// ```
+pub struct Periodic<A, B = A>
+where
+    A: PeriodicEq<B> + ?Sized,
+    B: ?Sized,
+{
+    /// The period after which the values repeat.
+    pub period: A::Epsilon,
+    /// The tolerance to use when testing values that are close together.
+    pub epsilon: A::Epsilon,
+    /// The relative tolerance for testing values that are far-apart, if any.
+    pub max_relative: Option<A::Epsilon>,
+    /// The ULPs to tolerate when testing values that are far-apart, if any.
+    pub max_ulps: Option<u32>,
+}
+
+impl<A, B> Default for Periodic<A, B>
+where
+    A: PeriodicEq<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn default() -> Periodic<A, B> {
+        Periodic {
+            period: A::default_period(),
+            epsilon: defaults::epsilon::<A, B>(A::default_epsilon()),
+            max_relative: None,
+            max_ulps: None,
+        }
+    }
+}
+
+impl<A, B> Periodic<A, B>
+where
+    A: PeriodicEq<B> + ?Sized,
+    B: ?Sized,
+{
+    /// Compare angles measured in radians. This is the same as [`Periodic::default`].
+    #[inline]
+    pub fn radians() -> Periodic<A, B> {
+        Periodic::default()
+    }
+
+    /// Compare angles measured in degrees.
+    #[inline]
+    pub fn degrees() -> Periodic<A, B>
+    where
+        A::Epsilon: num_traits::NumCast,
+    {
+        Periodic::default().period(num_traits::cast(360).unwrap())
+    }
+
+    /// Replace the period with the one specified.
+    #[inline]
+    pub fn period(self, period: A::Epsilon) -> Periodic<A, B> {
+        Periodic { period, ..self }
+    }
+
+    /// Replace the epsilon value with the one specified.
+    #[inline]
+    pub fn epsilon(self, epsilon: A::Epsilon) -> Periodic<A, B> {
+        Periodic { epsilon, ..self }
+    }
+
+    /// Also accept values that are equal under a relative comparison with the maximum relative
+    /// value specified.
+    #[inline]
+    pub fn max_relative(self, max_relative: A::Epsilon) -> Periodic<A, B> {
+        Periodic {
+            max_relative: Some(max_relative),
+            ..self
+        }
+    }
+
+    /// Also accept values that are equal under an ULPs comparison with the max ulps value
+    /// specified.
+    #[inline]
+    pub fn max_ulps(self, max_ulps: u32) -> Periodic<A, B> {
+        Periodic {
+            max_ulps: Some(max_ulps),
+            ..self
+        }
+    }
+
+    /// Peform the equality comparison
+    #[inline]
+    #[must_use]
+    pub fn eq(self, lhs: &A, rhs: &B) -> bool
+    where
+        A::Epsilon: Clone,
+    {
+        let (period, epsilon) = (self.period, self.epsilon);
+
+        if A::periodic_abs_diff_eq(lhs, rhs, period.clone(), epsilon.clone()) {
+            return true;
+        }
+        if let Some(ref max_relative) = self.max_relative {
+            let max_relative = max_relative.clone();
+            if A::periodic_relative_eq(lhs, rhs, period.clone(), epsilon.clone(), max_relative) {
+                return true;
+            }
+        }
+        match self.max_ulps {
+            Some(max_ulps) => {
+                A::periodic_ulps_eq(lhs, rhs, period.clone(), epsilon.clone(), max_ulps)
+            }
+            None => false,
+        }
+    }
+
+    /// Peform the inequality comparison
+    #[inline]
+    #[must_use]
+    pub fn ne(self, lhs: &A, rhs: &B) -> bool
+    where
+        A::Epsilon: Clone,
+    {
+        !self.eq(lhs, rhs)
+    }
+}
+
+// The comparison structs only contain tolerances, so these are implemented by hand to avoid the
+// bounds on `A` and `B` that `#[derive]` would add.
+macro_rules! impl_comparison_traits {
+    ($T:ident: $Eq:ident { $($field:ident),+ }) => {
+        impl<A, B> Clone for $T<A, B>
+        where
+            A: $Eq<B> + ?Sized,
+            B: ?Sized,
+            A::Epsilon: Clone,
+        {
+            #[inline]
+            fn clone(&self) -> $T<A, B> {
+                $T {
+                    $($field: self.$field.clone()),+
+                }
+            }
+        }
+
+        impl<A, B> Copy for $T<A, B>
+        where
+            A: $Eq<B> + ?Sized,
+            B: ?Sized,
+            A::Epsilon: Copy,
+        {
+        }
+
+        impl<A, B> fmt::Debug for $T<A, B>
+        where
+            A: $Eq<B> + ?Sized,
+            B: ?Sized,
+            A::Epsilon: fmt::Debug,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_struct(stringify!($T))
+                    $(.field(stringify!($field), &self.$field))+
+                    .finish()
+            }
+        }
+
+        impl<A, B> PartialEq for $T<A, B>
+        where
+            A: $Eq<B> + ?Sized,
+            B: ?Sized,
+            A::Epsilon: PartialEq,
+        {
+            #[inline]
+            fn eq(&self, other: &$T<A, B>) -> bool {
+                $(self.$field == other.$field)&&+
+            }
+        }
+    };
+}
+
+impl_comparison_traits!(AbsDiff: AbsDiffEq { epsilon });
+impl_comparison_traits!(Relative: RelativeEq { epsilon, max_relative });
+impl_comparison_traits!(Ulps: UlpsEq { epsilon, max_ulps });
+#[cfg(feature = "std")]
+impl_comparison_traits!(LogRelative: LogRelativeEq { max_log_diff });
+impl_comparison_traits!(Periodic: PeriodicEq { period, epsilon, max_relative, max_ulps });
+impl_comparison_traits!(ScaledRelative: RelativeEq { epsilon, max_relative, scale });
+impl_comparison_traits!(ScaledUlps: UlpsEq { epsilon, max_ulps, scale });
@@ -0,0 +1,212 @@
+use core::cell;
+use num_traits::Float;
+#[cfg(feature = "ordered-float")]
+use ordered_float::{NotNan, OrderedFloat};
+use AbsDiffEq;
+
+/// Equality comparisons between two numbers using the difference of their logarithms.
+///
+/// For two numbers `a` and `b` with the same sign, if `|ln|a| - ln|b|| <= max_log_diff`, then the
+/// two numbers are considered to be equal. This treats every order of magnitude alike, which is
+/// useful for values that span many orders of magnitude, such as probabilities.
+///
+/// The logarithms are computed separately, so the comparison does not overflow or underflow even
+/// for values at opposite ends of the range of the type. Numbers with different signs are never
+/// equal, and zero is only equal to zero.
+///
+/// `log_relative_eq`, `log_relative_ne`, `assert_log_relative_eq`, and `assert_log_relative_ne`
+/// macros are all wrappers of the `log_relative_eq` function in this trait.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate approx;
+/// # fn main() {
+/// assert_log_relative_eq!(1e-300f64, 1.01e-300f64, max_log_diff = 0.01);
+/// assert_log_relative_ne!(1e-300f64, 1.02e-300f64, max_log_diff = 0.01);
+/// assert_log_relative_eq!(1e10f64, 1.02e10f64, max_decibels = 0.1);
+/// # }
+/// ```
+pub trait LogRelativeEq<Rhs = Self>: AbsDiffEq<Rhs>
+where
+    Rhs: ?Sized,
+{
+    /// The default tolerance for the difference of the natural logarithms of the values.
+    ///
+    /// This is used when no `max_log_diff` value is supplied to the `log_relative_eq!` macro.
+    fn default_max_log_diff() -> Self::Epsilon;
+
+    /// A test for equality that uses the difference of the natural logarithms of the values.
+    fn log_relative_eq(&self, other: &Rhs, max_log_diff: Self::Epsilon) -> bool;
+
+    /// The inverse of [`LogRelativeEq::log_relative_eq`].
+    fn log_relative_ne(&self, other: &Rhs, max_log_diff: Self::Epsilon) -> bool {
+        !Self::log_relative_eq(self, other, max_log_diff)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Base implementations
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! impl_log_relative_eq {
+    ($T:ident) => {
+        impl LogRelativeEq for $T {
+            #[inline]
+            fn default_max_log_diff() -> $T {
+                Float::epsilon()
+            }
+
+            #[inline]
+            fn log_relative_eq(&self, other: &$T, max_log_diff: $T) -> bool {
+                // Handle same infinities and zeros
+                if self == other {
+                    return true;
+                }
+
+                // Handle remaining infinities and zeros
+                if !Float::is_finite(*self) || !Float::is_finite(*other) {
+                    return false;
+                }
+                if *self == 0.0 || *other == 0.0 {
+                    return false;
+                }
+
+                // Handle different signs
+                if Float::is_sign_negative(*self) != Float::is_sign_negative(*other) {
+                    return false;
+                }
+
+                let log_self = Float::ln(Float::abs(*self));
+                let log_other = Float::ln(Float::abs(*other));
+
+                Float::abs(log_self - log_other) <= max_log_diff
+            }
+        }
+    };
+}
+
+impl_log_relative_eq!(f32);
+impl_log_relative_eq!(f64);
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Derived implementations
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'a, T: LogRelativeEq + ?Sized> LogRelativeEq for &'a T {
+    #[inline]
+    fn default_max_log_diff() -> T::Epsilon {
+        T::default_max_log_diff()
+    }
+
+    #[inline]
+    fn log_relative_eq(&self, other: &&'a T, max_log_diff: T::Epsilon) -> bool {
+        T::log_relative_eq(*self, *other, max_log_diff)
+    }
+}
+
+impl<'a, T: LogRelativeEq + ?Sized> LogRelativeEq for &'a mut T {
+    #[inline]
+    fn default_max_log_diff() -> T::Epsilon {
+        T::default_max_log_diff()
+    }
+
+    #[inline]
+    fn log_relative_eq(&self, other: &&'a mut T, max_log_diff: T::Epsilon) -> bool {
+        T::log_relative_eq(*self, *other, max_log_diff)
+    }
+}
+
+impl<T: LogRelativeEq + Copy> LogRelativeEq for cell::Cell<T> {
+    #[inline]
+    fn default_max_log_diff() -> T::Epsilon {
+        T::default_max_log_diff()
+    }
+
+    #[inline]
+    fn log_relative_eq(&self, other: &cell::Cell<T>, max_log_diff: T::Epsilon) -> bool {
+        T::log_relative_eq(&self.get(), &other.get(), max_log_diff)
+    }
+}
+
+impl<T: LogRelativeEq + ?Sized> LogRelativeEq for cell::RefCell<T> {
+    #[inline]
+    fn default_max_log_diff() -> T::Epsilon {
+        T::default_max_log_diff()
+    }
+
+    #[inline]
+    fn log_relative_eq(&self, other: &cell::RefCell<T>, max_log_diff: T::Epsilon) -> bool {
+        T::log_relative_eq(&self.borrow(), &other.borrow(), max_log_diff)
+    }
+}
+
+impl<A, B> LogRelativeEq<[B]> for [A]
+where
+    A: LogRelativeEq<B>,
+    A::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_log_diff() -> A::Epsilon {
+        A::default_max_log_diff()
+    }
+
+    #[inline]
+    fn log_relative_eq(&self, other: &[B], max_log_diff: A::Epsilon) -> bool {
+        self.len() == other.len()
+            && Iterator::zip(self.iter(), other)
+                .all(|(x, y)| A::log_relative_eq(x, y, max_log_diff.clone()))
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+impl<T: LogRelativeEq + Copy> LogRelativeEq for NotNan<T> {
+    #[inline]
+    fn default_max_log_diff() -> T::Epsilon {
+        T::default_max_log_diff()
+    }
+
+    #[inline]
+    fn log_relative_eq(&self, other: &NotNan<T>, max_log_diff: T::Epsilon) -> bool {
+        T::log_relative_eq(&self.into_inner(), &other.into_inner(), max_log_diff)
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+impl<T: LogRelativeEq + Float> LogRelativeEq<T> for NotNan<T> {
+    #[inline]
+    fn default_max_log_diff() -> T::Epsilon {
+        T::default_max_log_diff()
+    }
+
+    #[inline]
+    fn log_relative_eq(&self, other: &T, max_log_diff: T::Epsilon) -> bool {
+        T::log_relative_eq(&self.into_inner(), other, max_log_diff)
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+impl<T: LogRelativeEq + Float> LogRelativeEq for OrderedFloat<T> {
+    #[inline]
+    fn default_max_log_diff() -> T::Epsilon {
+        T::default_max_log_diff()
+    }
+
+    #[inline]
+    fn log_relative_eq(&self, other: &OrderedFloat<T>, max_log_diff: T::Epsilon) -> bool {
+        T::log_relative_eq(&self.into_inner(), &other.into_inner(), max_log_diff)
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+impl<T: LogRelativeEq + Float> LogRelativeEq<T> for OrderedFloat<T> {
+    #[inline]
+    fn default_max_log_diff() -> T::Epsilon {
+        T::default_max_log_diff()
+    }
+
+    #[inline]
+    fn log_relative_eq(&self, other: &T, max_log_diff: T::Epsilon) -> bool {
+        T::log_relative_eq(&self.into_inner(), other, max_log_diff)
+    }
+}
@@ -12,14 +12,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+/// Check the value of a tolerance option in debug builds of the calling crate, panicking if it is
+/// negative, NaN or infinite.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tolerance {
+    ($opt:ident, $val:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::{__CheckFloat, __CheckOther};
+        let value = $val;
+        if cfg!(debug_assertions) {
+            (&$crate::__Check(&value)).check(stringify!($opt), stringify!($val));
+        }
+        value
+    }};
+}
+
 /// Approximate equality of using the absolute difference.
 #[macro_export]
 macro_rules! abs_diff_eq {
     ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
-        $crate::AbsDiff::default()$(.$opt($val))*.eq(&$lhs, &$rhs)
+        $crate::AbsDiff::default()$(.$opt($crate::__tolerance!($opt, $val)))*.eq(&$lhs, &$rhs)
     };
     ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
-        $crate::AbsDiff::default()$(.$opt($val))*.eq(&$lhs, &$rhs)
+        $crate::AbsDiff::default()$(.$opt($crate::__tolerance!($opt, $val)))*.eq(&$lhs, &$rhs)
     };
 }
 
@@ -27,10 +43,10 @@ macro_rules! abs_diff_eq {
 #[macro_export]
 macro_rules! abs_diff_ne {
     ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
-        $crate::AbsDiff::default()$(.$opt($val))*.ne(&$lhs, &$rhs)
+        $crate::AbsDiff::default()$(.$opt($crate::__tolerance!($opt, $val)))*.ne(&$lhs, &$rhs)
     };
     ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
-        $crate::AbsDiff::default()$(.$opt($val))*.ne(&$lhs, &$rhs)
+        $crate::AbsDiff::default()$(.$opt($crate::__tolerance!($opt, $val)))*.ne(&$lhs, &$rhs)
     };
 }
 
@@ -38,10 +54,10 @@ macro_rules! abs_diff_ne {
 #[macro_export]
 macro_rules! relative_eq {
     ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
-        $crate::Relative::default()$(.$opt($val))*.eq(&$lhs, &$rhs)
+        $crate::Relative::default()$(.$opt($crate::__tolerance!($opt, $val)))*.eq(&$lhs, &$rhs)
     };
     ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
-        $crate::Relative::default()$(.$opt($val))*.eq(&$lhs, &$rhs)
+        $crate::Relative::default()$(.$opt($crate::__tolerance!($opt, $val)))*.eq(&$lhs, &$rhs)
     };
 }
 
@@ -49,10 +65,10 @@ macro_rules! relative_eq {
 #[macro_export]
 macro_rules! relative_ne {
     ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
-        $crate::Relative::default()$(.$opt($val))*.ne(&$lhs, &$rhs)
+        $crate::Relative::default()$(.$opt($crate::__tolerance!($opt, $val)))*.ne(&$lhs, &$rhs)
     };
     ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
-        $crate::Relative::default()$(.$opt($val))*.ne(&$lhs, &$rhs)
+        $crate::Relative::default()$(.$opt($crate::__tolerance!($opt, $val)))*.ne(&$lhs, &$rhs)
     };
 }
 
@@ -60,10 +76,10 @@ macro_rules! relative_ne {
 #[macro_export]
 macro_rules! ulps_eq {
     ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
-        $crate::Ulps::default()$(.$opt($val))*.eq(&$lhs, &$rhs)
+        $crate::Ulps::default()$(.$opt($crate::__tolerance!($opt, $val)))*.eq(&$lhs, &$rhs)
     };
     ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
-        $crate::Ulps::default()$(.$opt($val))*.eq(&$lhs, &$rhs)
+        $crate::Ulps::default()$(.$opt($crate::__tolerance!($opt, $val)))*.eq(&$lhs, &$rhs)
     };
 }
 
@@ -71,51 +87,333 @@ macro_rules! ulps_eq {
 #[macro_export]
 macro_rules! ulps_ne {
     ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
-        $crate::Ulps::default()$(.$opt($val))*.ne(&$lhs, &$rhs)
+        $crate::Ulps::default()$(.$opt($crate::__tolerance!($opt, $val)))*.ne(&$lhs, &$rhs)
     };
     ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
-        $crate::Ulps::default()$(.$opt($val))*.ne(&$lhs, &$rhs)
+        $crate::Ulps::default()$(.$opt($crate::__tolerance!($opt, $val)))*.ne(&$lhs, &$rhs)
+    };
+}
+
+/// Approximate equality using a combination of absolute, relative and ULPs based comparisons.
+///
+/// The `abs`, `rel` and `ulps` options each add a comparison, and the values are considered
+/// equal if any of them consider the values to be equal, or if all of them do with
+/// `mode = all`. With no options this is the same as [`relative_eq!`].
+#[macro_export(local_inner_macros)]
+macro_rules! approx_eq {
+    ($lhs:expr, $rhs:expr) => {
+        $crate::Relative::default().eq(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr,) => {
+        $crate::Relative::default().eq(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr, $($opts:tt)+) => {
+        $crate::Comparator::compare(&__approx_comparator!(@parse Or [] $($opts)+), &$lhs, &$rhs)
+    };
+}
+
+/// Approximate inequality using a combination of absolute, relative and ULPs based comparisons.
+#[macro_export(local_inner_macros)]
+macro_rules! approx_ne {
+    ($lhs:expr, $rhs:expr) => {
+        $crate::Relative::default().ne(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr,) => {
+        $crate::Relative::default().ne(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr, $($opts:tt)+) => {
+        !$crate::Comparator::compare(&__approx_comparator!(@parse Or [] $($opts)+), &$lhs, &$rhs)
+    };
+}
+
+/// Approximate equality using a relative comparison for values that implement [`RelativeEq`],
+/// and `==` for other values.
+///
+/// # Generic code
+///
+/// The comparison is selected where the macro is expanded, not where the values are known.
+/// Inside a generic function the values are only compared relatively if the bounds of the
+/// function require `RelativeEq`; otherwise the macro **always falls back to `==`**, even when the
+/// function is called with floating point numbers. There is no way around this without
+/// specialization, so add a `RelativeEq` bound to generic code that should compare approximately:
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+///
+/// use approx::RelativeEq;
+///
+/// fn exact<T: PartialEq>(a: T, b: T) -> bool {
+///     approx_or_eq!(a, b)
+/// }
+///
+/// fn relative<T: RelativeEq>(a: T, b: T) -> bool {
+///     approx_or_eq!(a, b)
+/// }
+///
+/// # fn main() {
+/// assert!(!exact(0.1 + 0.2, 0.3));
+/// assert!(relative(0.1 + 0.2, 0.3));
+/// # }
+/// ```
+///
+/// [`RelativeEq`]: crate::RelativeEq
+#[macro_export]
+macro_rules! approx_or_eq {
+    ($lhs:expr, $rhs:expr $(,)*) => {{
+        #[allow(unused_imports)]
+        use $crate::{__ApproxOrPartialEq, __ApproxOrRelative};
+        (&$crate::__ApproxOr(&$lhs, &$rhs)).approx_or_eq()
+    }};
+}
+
+/// Approximate inequality using a relative comparison for values that implement [`RelativeEq`],
+/// and `!=` for other values.
+///
+/// Like [`approx_or_eq!`], this **always falls back to `!=`** inside a generic function whose
+/// bounds do not require `RelativeEq`. See [its documentation](approx_or_eq#generic-code).
+///
+/// [`RelativeEq`]: crate::RelativeEq
+#[macro_export]
+macro_rules! approx_or_ne {
+    ($lhs:expr, $rhs:expr $(,)*) => {{
+        #[allow(unused_imports)]
+        use $crate::{__ApproxOrPartialEq, __ApproxOrRelative};
+        !(&$crate::__ApproxOr(&$lhs, &$rhs)).approx_or_eq()
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! __approx_comparator {
+    (@parse $mode:ident [$($acc:tt)*]) => {
+        __approx_comparator!(@build $mode $($acc)*)
+    };
+    (@parse $mode:ident [$($acc:tt)*] , $($rest:tt)*) => {
+        __approx_comparator!(@parse $mode [$($acc)*] $($rest)*)
+    };
+    (@parse $mode:ident [$($acc:tt)*] mode = any $($rest:tt)*) => {
+        __approx_comparator!(@parse Or [$($acc)*] $($rest)*)
+    };
+    (@parse $mode:ident [$($acc:tt)*] mode = all $($rest:tt)*) => {
+        __approx_comparator!(@parse And [$($acc)*] $($rest)*)
+    };
+    (@parse $mode:ident [$($acc:tt)*] $opt:ident = $val:expr) => {
+        __approx_comparator!(@parse $mode [$($acc)* ($opt $val)])
+    };
+    (@parse $mode:ident [$($acc:tt)*] $opt:ident = $val:expr, $($rest:tt)*) => {
+        __approx_comparator!(@parse $mode [$($acc)* ($opt $val)] $($rest)*)
+    };
+    (@build $mode:ident) => {
+        $crate::Relative::default()
+    };
+    (@build $mode:ident ($opt:ident $val:expr)) => {
+        __approx_comparator!(@one $opt $val)
+    };
+    (@build $mode:ident ($opt:ident $val:expr) $($rest:tt)+) => {
+        $crate::$mode(
+            __approx_comparator!(@one $opt $val),
+            __approx_comparator!(@build $mode $($rest)+),
+        )
+    };
+    (@one abs $val:expr) => {
+        $crate::AbsDiff::default().epsilon(__tolerance!(abs, $val))
+    };
+    (@one rel $val:expr) => {
+        $crate::Relative::default()
+            .epsilon($crate::__zero())
+            .max_relative(__tolerance!(rel, $val))
+    };
+    (@one ulps $val:expr) => {
+        $crate::Ulps::default().epsilon($crate::__zero()).max_ulps($val)
+    };
+}
+
+/// Approximate equality to zero, using a relative comparison with the tolerance relative to the
+/// `scale` option.
+#[macro_export]
+macro_rules! approx_zero {
+    ($value:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::Relative::default()$(.$opt($crate::__tolerance!($opt, $val)))*.eq_zero(&$value)
+    };
+    ($value:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::Relative::default()$(.$opt($crate::__tolerance!($opt, $val)))*.eq_zero(&$value)
+    };
+}
+
+/// Approximate inequality to zero, using a relative comparison with the tolerance relative to
+/// the `scale` option.
+#[macro_export]
+macro_rules! approx_nonzero {
+    ($value:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::Relative::default()$(.$opt($crate::__tolerance!($opt, $val)))*.ne_zero(&$value)
+    };
+    ($value:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::Relative::default()$(.$opt($crate::__tolerance!($opt, $val)))*.ne_zero(&$value)
+    };
+}
+
+/// Approximate equality using the difference of the logarithms of the values.
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! log_relative_eq {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::LogRelative::default()$(.$opt($crate::__tolerance!($opt, $val)))*.eq(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::LogRelative::default()$(.$opt($crate::__tolerance!($opt, $val)))*.eq(&$lhs, &$rhs)
+    };
+}
+
+/// Approximate inequality using the difference of the logarithms of the values.
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! log_relative_ne {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::LogRelative::default()$(.$opt($crate::__tolerance!($opt, $val)))*.ne(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::LogRelative::default()$(.$opt($crate::__tolerance!($opt, $val)))*.ne(&$lhs, &$rhs)
+    };
+}
+
+/// Approximate equality of periodic quantities, such as angles, using the shortest distance
+/// around the period.
+#[macro_export]
+macro_rules! angle_eq {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::Periodic::default()$(.$opt($crate::__tolerance!($opt, $val)))*.eq(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::Periodic::default()$(.$opt($crate::__tolerance!($opt, $val)))*.eq(&$lhs, &$rhs)
+    };
+}
+
+/// Approximate inequality of periodic quantities, such as angles, using the shortest distance
+/// around the period.
+#[macro_export]
+macro_rules! angle_ne {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::Periodic::default()$(.$opt($crate::__tolerance!($opt, $val)))*.ne(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::Periodic::default()$(.$opt($crate::__tolerance!($opt, $val)))*.ne(&$lhs, &$rhs)
+    };
+}
+
+/// Equality of the underlying bit patterns.
+#[macro_export]
+macro_rules! bit_eq {
+    ($lhs:expr, $rhs:expr) => {
+        $crate::BitEq::bit_eq(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr,) => {
+        $crate::BitEq::bit_eq(&$lhs, &$rhs)
+    };
+}
+
+/// Inequality of the underlying bit patterns.
+#[macro_export]
+macro_rules! bit_ne {
+    ($lhs:expr, $rhs:expr) => {
+        $crate::BitEq::bit_ne(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr,) => {
+        $crate::BitEq::bit_ne(&$lhs, &$rhs)
+    };
+}
+
+/// Equality to a number of significant figures.
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! sig_figs_eq {
+    ($lhs:expr, $rhs:expr, sig_figs = $n:expr) => {
+        $crate::SigFigs::new($n).eq(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr, sig_figs = $n:expr,) => {
+        $crate::SigFigs::new($n).eq(&$lhs, &$rhs)
+    };
+}
+
+/// Inequality to a number of significant figures.
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! sig_figs_ne {
+    ($lhs:expr, $rhs:expr, sig_figs = $n:expr) => {
+        $crate::SigFigs::new($n).ne(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr, sig_figs = $n:expr,) => {
+        $crate::SigFigs::new($n).ne(&$lhs, &$rhs)
+    };
+}
+
+/// Equality to a number of decimal places.
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! places_eq {
+    ($lhs:expr, $rhs:expr, places = $n:expr) => {
+        $crate::DecimalPlaces::new($n).eq(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr, places = $n:expr,) => {
+        $crate::DecimalPlaces::new($n).eq(&$lhs, &$rhs)
+    };
+}
+
+/// Inequality to a number of decimal places.
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! places_ne {
+    ($lhs:expr, $rhs:expr, places = $n:expr) => {
+        $crate::DecimalPlaces::new($n).ne(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr, places = $n:expr,) => {
+        $crate::DecimalPlaces::new($n).ne(&$lhs, &$rhs)
     };
 }
 
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __assert_approx {
-    ($eq:ident, $given:expr, $expected:expr) => {{
+    (@named $name:expr, $eq:ident, $given:expr, $expected:expr) => {{
         match (&($given), &($expected)) {
-            (given, expected) => assert!(
+            (given, expected) => $crate::__assert_defaults(|| assert!(
                 $eq!(*given, *expected),
-"assert_{}!({}, {})
+"{}!({}, {})
 
     left  = {:?}
     right = {:?}
-
+{}
 ",
-                stringify!($eq),
+                $name,
                 stringify!($given),
                 stringify!($expected),
-                given, expected,
-            ),
+                given, expected, $crate::__DefaultsNote,
+            )),
         }
     }};
-    ($eq:ident, $given:expr, $expected:expr, $($opt:ident = $val:expr),+) => {{
+    (@named $name:expr, $eq:ident, $given:expr, $expected:expr, $($opt:ident = $val:expr),+) => {{
         match (&($given), &($expected)) {
-            (given, expected) => assert!(
+            (given, expected) => $crate::__assert_defaults(|| assert!(
                 $eq!(*given, *expected, $($opt = $val),+),
-"assert_{}!({}, {}, {})
+"{}!({}, {}, {})
 
     left  = {:?}
     right = {:?}
-
+{}
 ",
-                stringify!($eq),
+                $name,
                 stringify!($given),
                 stringify!($expected),
                 stringify!($($opt = $val),+),
-                given, expected,
-            ),
+                given, expected, $crate::__DefaultsNote,
+            )),
         }
     }};
+    ($eq:ident, $given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::__assert_approx!(
+            @named concat!("assert_", stringify!($eq)), $eq, $given, $expected $(, $opt = $val)*
+        )
+    };
 }
 
 /// An assertion that delegates to [`abs_diff_eq!`], and panics with a helpful error on failure.
@@ -183,3 +481,594 @@ macro_rules! assert_ulps_ne {
         __assert_approx!(ulps_ne, $given, $expected $(, $opt = $val)*)
     };
 }
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_zero {
+    ($eq:ident, $value:expr) => {{
+        match &($value) {
+            value => $crate::__assert_defaults(|| assert!(
+                $eq!(*value),
+"assert_{}!({})
+
+    value = {:?}
+{}
+",
+                stringify!($eq),
+                stringify!($value),
+                value, $crate::__DefaultsNote,
+            )),
+        }
+    }};
+    ($eq:ident, $value:expr, $($opt:ident = $val:expr),+) => {{
+        match &($value) {
+            value => $crate::__assert_defaults(|| assert!(
+                $eq!(*value, $($opt = $val),+),
+"assert_{}!({}, {})
+
+    value = {:?}
+{}
+",
+                stringify!($eq),
+                stringify!($value),
+                stringify!($($opt = $val),+),
+                value, $crate::__DefaultsNote,
+            )),
+        }
+    }};
+}
+
+/// An assertion that delegates to [`approx_zero!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_approx_zero {
+    ($value:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_zero!(approx_zero, $value $(, $opt = $val)*)
+    };
+    ($value:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_zero!(approx_zero, $value $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`approx_nonzero!`], and panics with a helpful error on
+/// failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_approx_nonzero {
+    ($value:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_zero!(approx_nonzero, $value $(, $opt = $val)*)
+    };
+    ($value:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_zero!(approx_nonzero, $value $(, $opt = $val)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_approx_tt {
+    ($eq:ident, $given:expr, $expected:expr, $($opts:tt)+) => {{
+        match (&($given), &($expected)) {
+            (given, expected) => $crate::__assert_defaults(|| assert!(
+                $eq!(*given, *expected, $($opts)+),
+"assert_{}!({}, {}, {})
+
+    left  = {:?}
+    right = {:?}
+{}
+",
+                stringify!($eq),
+                stringify!($given),
+                stringify!($expected),
+                stringify!($($opts)+),
+                given, expected, $crate::__DefaultsNote,
+            )),
+        }
+    }};
+}
+
+/// An assertion that delegates to [`approx_eq!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_approx_eq {
+    ($given:expr, $expected:expr) => {
+        __assert_approx!(approx_eq, $given, $expected)
+    };
+    ($given:expr, $expected:expr,) => {
+        __assert_approx!(approx_eq, $given, $expected)
+    };
+    ($given:expr, $expected:expr, $($opts:tt)+) => {
+        __assert_approx_tt!(approx_eq, $given, $expected, $($opts)+)
+    };
+}
+
+/// An assertion that delegates to [`approx_ne!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_approx_ne {
+    ($given:expr, $expected:expr) => {
+        __assert_approx!(approx_ne, $given, $expected)
+    };
+    ($given:expr, $expected:expr,) => {
+        __assert_approx!(approx_ne, $given, $expected)
+    };
+    ($given:expr, $expected:expr, $($opts:tt)+) => {
+        __assert_approx_tt!(approx_ne, $given, $expected, $($opts)+)
+    };
+}
+
+/// An assertion that delegates to [`approx_or_eq!`], and panics with a helpful error on failure.
+///
+/// Inside a generic function whose bounds do not require [`RelativeEq`], this **always falls
+/// back to `==`**. See [`approx_or_eq!`](approx_or_eq#generic-code).
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+///
+/// # fn main() {
+/// assert_approx_or_eq!(0.1 + 0.2, 0.3);
+/// assert_approx_or_eq!("a", "a");
+/// assert_approx_or_ne!(Some(1), None);
+/// # }
+/// ```
+///
+/// [`RelativeEq`]: crate::RelativeEq
+#[macro_export(local_inner_macros)]
+macro_rules! assert_approx_or_eq {
+    ($given:expr, $expected:expr) => {
+        __assert_approx!(approx_or_eq, $given, $expected)
+    };
+    ($given:expr, $expected:expr,) => {
+        __assert_approx!(approx_or_eq, $given, $expected)
+    };
+}
+
+/// An assertion that delegates to [`approx_or_ne!`], and panics with a helpful error on failure.
+///
+/// Inside a generic function whose bounds do not require [`RelativeEq`], this **always falls
+/// back to `!=`**. See [`approx_or_eq!`](approx_or_eq#generic-code).
+///
+/// [`RelativeEq`]: crate::RelativeEq
+#[macro_export(local_inner_macros)]
+macro_rules! assert_approx_or_ne {
+    ($given:expr, $expected:expr) => {
+        __assert_approx!(approx_or_ne, $given, $expected)
+    };
+    ($given:expr, $expected:expr,) => {
+        __assert_approx!(approx_or_ne, $given, $expected)
+    };
+}
+
+/// An assertion that delegates to [`log_relative_eq!`], and panics with a helpful error on
+/// failure.
+#[cfg(feature = "std")]
+#[macro_export(local_inner_macros)]
+macro_rules! assert_log_relative_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx!(log_relative_eq, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx!(log_relative_eq, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`log_relative_ne!`], and panics with a helpful error on
+/// failure.
+#[cfg(feature = "std")]
+#[macro_export(local_inner_macros)]
+macro_rules! assert_log_relative_ne {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx!(log_relative_ne, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx!(log_relative_ne, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`angle_eq!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_angle_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx!(angle_eq, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx!(angle_eq, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`angle_ne!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_angle_ne {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx!(angle_ne, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx!(angle_ne, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_bits {
+    ($eq:ident, $given:expr, $expected:expr) => {{
+        match (&($given), &($expected)) {
+            (given, expected) => assert!(
+                $eq!(*given, *expected),
+                "assert_{}!({}, {})
+
+    left  = {:?}
+    right = {:?}
+
+    left bits  = {:?}
+    right bits = {:?}
+
+",
+                stringify!($eq),
+                stringify!($given),
+                stringify!($expected),
+                given,
+                expected,
+                $crate::Bits(given),
+                $crate::Bits(expected),
+            ),
+        }
+    }};
+}
+
+/// An assertion that delegates to [`bit_eq!`], and panics with the differing bit patterns on
+/// failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_bit_eq {
+    ($given:expr, $expected:expr) => {
+        __assert_bits!(bit_eq, $given, $expected)
+    };
+    ($given:expr, $expected:expr,) => {
+        __assert_bits!(bit_eq, $given, $expected)
+    };
+}
+
+/// An assertion that delegates to [`bit_ne!`], and panics with the bit patterns on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_bit_ne {
+    ($given:expr, $expected:expr) => {
+        __assert_bits!(bit_ne, $given, $expected)
+    };
+    ($given:expr, $expected:expr,) => {
+        __assert_bits!(bit_ne, $given, $expected)
+    };
+}
+
+#[doc(hidden)]
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! __assert_sig_figs {
+    ($eq:ident, $given:expr, $expected:expr, $n:expr) => {{
+        match (&($given), &($expected), $n) {
+            (given, expected, n) => assert!(
+                $eq!(*given, *expected, sig_figs = n),
+                "assert_{}!({}, {}, sig_figs = {})
+
+    left  = {:.*e} ({:?})
+    right = {:.*e} ({:?})
+    diff  = {:?} (half a unit = {:?})
+
+",
+                stringify!($eq),
+                stringify!($given),
+                stringify!($expected),
+                n,
+                (n as usize).saturating_sub(1),
+                given,
+                given,
+                (n as usize).saturating_sub(1),
+                expected,
+                expected,
+                (*given - *expected).abs(),
+                $crate::SigFigs::new(n).tolerance(given, expected),
+            ),
+        }
+    }};
+}
+
+/// An assertion that delegates to [`sig_figs_eq!`], and panics with both values, rounded to the
+/// requested number of significant figures and in full, and their difference on failure.
+#[cfg(feature = "std")]
+#[macro_export(local_inner_macros)]
+macro_rules! assert_sig_figs_eq {
+    ($given:expr, $expected:expr, sig_figs = $n:expr) => {
+        __assert_sig_figs!(sig_figs_eq, $given, $expected, $n)
+    };
+    ($given:expr, $expected:expr, sig_figs = $n:expr,) => {
+        __assert_sig_figs!(sig_figs_eq, $given, $expected, $n)
+    };
+}
+
+/// An assertion that delegates to [`sig_figs_ne!`], and panics with both values, rounded to the
+/// requested number of significant figures and in full, and their difference on failure.
+#[cfg(feature = "std")]
+#[macro_export(local_inner_macros)]
+macro_rules! assert_sig_figs_ne {
+    ($given:expr, $expected:expr, sig_figs = $n:expr) => {
+        __assert_sig_figs!(sig_figs_ne, $given, $expected, $n)
+    };
+    ($given:expr, $expected:expr, sig_figs = $n:expr,) => {
+        __assert_sig_figs!(sig_figs_ne, $given, $expected, $n)
+    };
+}
+
+#[doc(hidden)]
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! __assert_places {
+    ($eq:ident, $given:expr, $expected:expr, $n:expr) => {{
+        match (&($given), &($expected), $n) {
+            (given, expected, n) => assert!(
+                $eq!(*given, *expected, places = n),
+                "assert_{}!({}, {}, places = {})
+
+    left  = {:.*} ({:?})
+    right = {:.*} ({:?})
+    diff  = {:?} (half a unit = {:?})
+
+",
+                stringify!($eq),
+                stringify!($given),
+                stringify!($expected),
+                n,
+                n as usize,
+                given,
+                given,
+                n as usize,
+                expected,
+                expected,
+                (*given - *expected).abs(),
+                $crate::__places_tolerance(n, given),
+            ),
+        }
+    }};
+}
+
+/// An assertion that delegates to [`places_eq!`], and panics with both values, rounded to the
+/// requested number of decimal places and in full, and their difference on failure.
+#[cfg(feature = "std")]
+#[macro_export(local_inner_macros)]
+macro_rules! assert_places_eq {
+    ($given:expr, $expected:expr, places = $n:expr) => {
+        __assert_places!(places_eq, $given, $expected, $n)
+    };
+    ($given:expr, $expected:expr, places = $n:expr,) => {
+        __assert_places!(places_eq, $given, $expected, $n)
+    };
+}
+
+/// An assertion that delegates to [`places_ne!`], and panics with both values, rounded to the
+/// requested number of decimal places and in full, and their difference on failure.
+#[cfg(feature = "std")]
+#[macro_export(local_inner_macros)]
+macro_rules! assert_places_ne {
+    ($given:expr, $expected:expr, places = $n:expr) => {
+        __assert_places!(places_ne, $given, $expected, $n)
+    };
+    ($given:expr, $expected:expr, places = $n:expr,) => {
+        __assert_places!(places_ne, $given, $expected, $n)
+    };
+}
+
+#[doc(hidden)]
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! __assert_fields {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {{
+        match (&($given), &($expected)) {
+            (given, expected) => $crate::__assert_defaults(|| {
+                let tolerance = $crate::Tolerance::from(
+                    $crate::__fields_relative(given)$(.$opt($crate::__tolerance!($opt, $val)))*
+                );
+                let mismatches = $crate::field_mismatches(given, expected, &tolerance);
+                assert!(
+                    mismatches.is_empty(),
+"assert_fields_eq!({}, {}{}{})
+
+    left  = {:?}
+    right = {:?}
+{}
+    mismatched fields:
+{}
+",
+                    stringify!($given),
+                    stringify!($expected),
+                    if stringify!($($opt)*).is_empty() { "" } else { ", " },
+                    stringify!($($opt = $val),*),
+                    given, expected, $crate::__DefaultsNote,
+                    $crate::__FieldReport(&mismatches),
+                )
+            }),
+        }
+    }};
+    (@ne $given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {{
+        match (&($given), &($expected)) {
+            (given, expected) => $crate::__assert_defaults(|| {
+                let tolerance = $crate::Tolerance::from(
+                    $crate::__fields_relative(given)$(.$opt($crate::__tolerance!($opt, $val)))*
+                );
+                assert!(
+                    !$crate::field_mismatches(given, expected, &tolerance).is_empty(),
+"assert_fields_ne!({}, {}{}{})
+
+    left  = {:?}
+    right = {:?}
+{}
+",
+                    stringify!($given),
+                    stringify!($expected),
+                    if stringify!($($opt)*).is_empty() { "" } else { ", " },
+                    stringify!($($opt = $val),*),
+                    given, expected, $crate::__DefaultsNote,
+                )
+            }),
+        }
+    }};
+}
+
+/// An assertion that compares each of the fields of two [`ApproxFields`](crate::ApproxFields)
+/// values using a relative comparison, and panics listing the path of every mismatched field on
+/// failure.
+///
+/// The options are those of [`relative_eq!`].
+#[cfg(feature = "std")]
+#[macro_export(local_inner_macros)]
+macro_rules! assert_fields_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_fields!($given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_fields!($given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that at least one of the fields of two [`ApproxFields`](crate::ApproxFields)
+/// values differs using a relative comparison, and panics with a helpful error on failure.
+///
+/// The options are those of [`relative_ne!`].
+#[cfg(feature = "std")]
+#[macro_export(local_inner_macros)]
+macro_rules! assert_fields_ne {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_fields!(@ne $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_fields!(@ne $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// Implement [`AbsDiffEq`](crate::AbsDiffEq), [`RelativeEq`](crate::RelativeEq) and
+/// [`UlpsEq`](crate::UlpsEq) for a struct by comparing each of the listed fields in turn, using
+/// the default tolerances of a scalar type.
+///
+/// For a generic struct the first type parameter is the scalar type, and for a struct that is not
+/// generic the scalar type follows `as`. Tuple structs list the indices of their fields. The
+/// fields must implement the traits with the same `Epsilon` as the scalar type.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Point<T> {
+///     x: T,
+///     y: T,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Meters(f32);
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Segment {
+///     start: Point<f64>,
+///     end: Point<f64>,
+/// }
+///
+/// impl_approx!(Point<T> { x, y });
+/// impl_approx!(Meters(0) as f32);
+/// impl_approx!(Segment { start, end } as f64);
+///
+/// # fn main() {
+/// let (a, b) = (Point { x: 1.0, y: 2.0 }, Point { x: 1.0, y: 2.0 + 1e-12 });
+/// assert_relative_eq!(a, b, max_relative = 1e-9);
+/// assert_relative_ne!(a, b);
+/// assert_ulps_ne!(Meters(1.0), Meters(1.1));
+///
+/// let start = Point { x: 0.0, y: 0.0 };
+/// assert_abs_diff_eq!(
+///     Segment { start, end: Point { x: 1.0, y: 1.0 } },
+///     Segment { start: Point { x: 0.0, y: 1e-3 }, end: Point { x: 1.0, y: 1.0 } },
+///     epsilon = 1e-2
+/// );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! impl_approx {
+    ($name:ident < $T:ident $(, $P:ident)* > { $($field:ident),+ $(,)* }) => {
+        $crate::impl_approx!(@impl [$T $(, $P)*] $name<$T $(, $P)*>, $T, $($field),+);
+    };
+    ($name:ident < $T:ident $(, $P:ident)* > ( $($field:tt),+ )) => {
+        $crate::impl_approx!(@impl [$T $(, $P)*] $name<$T $(, $P)*>, $T, $($field),+);
+    };
+    ($name:ident { $($field:ident),+ $(,)* } as $T:ty) => {
+        $crate::impl_approx!(@impl [] $name, $T, $($field),+);
+    };
+    ($name:ident ( $($field:tt),+ ) as $T:ty) => {
+        $crate::impl_approx!(@impl [] $name, $T, $($field),+);
+    };
+    (@impl [$($generics:tt)*] $Self:ty, $T:ty, $($field:tt),+) => {
+        impl<$($generics)*> $crate::AbsDiffEq for $Self
+        where
+            $T: $crate::AbsDiffEq,
+            <$T as $crate::AbsDiffEq>::Epsilon: Clone,
+        {
+            type Epsilon = <$T as $crate::AbsDiffEq>::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> Self::Epsilon {
+                <$T as $crate::AbsDiffEq>::default_epsilon()
+            }
+
+            #[inline]
+            fn scale_epsilon(epsilon: &Self::Epsilon, scale: f64) -> Option<Self::Epsilon> {
+                <$T as $crate::AbsDiffEq>::scale_epsilon(epsilon, scale)
+            }
+
+            #[inline]
+            fn epsilon_from_f64(value: f64) -> Option<Self::Epsilon> {
+                <$T as $crate::AbsDiffEq>::epsilon_from_f64(value)
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                $($crate::AbsDiffEq::abs_diff_eq(&self.$field, &other.$field, epsilon.clone()))&&+
+            }
+        }
+
+        impl<$($generics)*> $crate::RelativeEq for $Self
+        where
+            $T: $crate::RelativeEq,
+            <$T as $crate::AbsDiffEq>::Epsilon: Clone,
+        {
+            #[inline]
+            fn default_max_relative() -> Self::Epsilon {
+                <$T as $crate::RelativeEq>::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                $($crate::RelativeEq::relative_eq(
+                    &self.$field,
+                    &other.$field,
+                    epsilon.clone(),
+                    max_relative.clone(),
+                ))&&+
+            }
+        }
+
+        impl<$($generics)*> $crate::UlpsEq for $Self
+        where
+            $T: $crate::UlpsEq,
+            <$T as $crate::AbsDiffEq>::Epsilon: Clone,
+        {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                <$T as $crate::UlpsEq>::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                $($crate::UlpsEq::ulps_eq(&self.$field, &other.$field, epsilon.clone(), max_ulps))&&+
+            }
+        }
+    };
+}
@@ -0,0 +1,185 @@
+use core::fmt;
+use {AbsDiffEq, RelativeEq, UlpsEq};
+
+/// A number that is compared with numbers of another type, in the lower precision of the two.
+///
+/// Comparing an `f32` with an `f64` rounds the `f64` to `f32`, and comparing an integer with a
+/// float converts the integer to the float type, so the `Epsilon` is always the float type that
+/// the values are compared as, and ULPs are counted in that type. This includes `PartialEq`,
+/// which compares the values after the same conversion.
+///
+/// The comparisons are implemented for the wrapper rather than directly between the primitive
+/// types, such as `AbsDiffEq<f64> for f32`. With more than one implementation of each trait for
+/// `f32` and `f64`, the right hand side could no longer be inferred: calls such as
+/// `f64::default_epsilon()`, which implementations for custom types rely on, would need the
+/// trait spelled out, and an unsuffixed literal compared with an `f32` would be inferred as an
+/// `f64`. For the same reason, the approximate comparisons need the left hand side to be
+/// wrapped, but the right hand side may be wrapped as well, so either value can be passed as
+/// `Mixed`. `PartialEq` is also implemented with the wrapper on the right.
+///
+/// The `Debug` output is that of the wrapped value.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+///
+/// use approx::Mixed;
+///
+/// # fn main() {
+/// let kernel: f32 = 0.1 + 0.2;
+/// let reference: f64 = 0.3;
+///
+/// assert_relative_eq!(Mixed(kernel), reference);
+/// assert_ulps_eq!(Mixed(reference), kernel, max_ulps = 1);
+/// assert_abs_diff_eq!(Mixed(3), 3.05f32, epsilon = 0.1f32);
+/// assert_relative_ne!(Mixed(1.0f32), 1.001f64, max_relative = 1e-4);
+/// assert_relative_eq!(Mixed(reference), Mixed(kernel));
+/// assert!(reference == Mixed(kernel));
+/// # }
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Mixed<T>(pub T);
+
+impl<T: fmt::Debug> fmt::Debug for Mixed<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+macro_rules! impl_mixed {
+    ($T:ident, $Rhs:ident, $F:ident) => {
+        impl PartialEq<$Rhs> for Mixed<$T> {
+            #[inline]
+            fn eq(&self, other: &$Rhs) -> bool {
+                self.0 as $F == *other as $F
+            }
+        }
+
+        impl AbsDiffEq<$Rhs> for Mixed<$T> {
+            type Epsilon = $F;
+
+            #[inline]
+            fn default_epsilon() -> $F {
+                $F::default_epsilon()
+            }
+
+            #[inline]
+            fn scale_epsilon(epsilon: &$F, scale: f64) -> Option<$F> {
+                $F::scale_epsilon(epsilon, scale)
+            }
+
+            #[inline]
+            fn epsilon_from_f64(value: f64) -> Option<$F> {
+                $F::epsilon_from_f64(value)
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$Rhs, epsilon: $F) -> bool {
+                $F::abs_diff_eq(&(self.0 as $F), &(*other as $F), epsilon)
+            }
+        }
+
+        impl RelativeEq<$Rhs> for Mixed<$T> {
+            #[inline]
+            fn default_max_relative() -> $F {
+                $F::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &$Rhs, epsilon: $F, max_relative: $F) -> bool {
+                $F::relative_eq(&(self.0 as $F), &(*other as $F), epsilon, max_relative)
+            }
+        }
+
+        impl UlpsEq<$Rhs> for Mixed<$T> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                $F::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$Rhs, epsilon: $F, max_ulps: u32) -> bool {
+                $F::ulps_eq(&(self.0 as $F), &(*other as $F), epsilon, max_ulps)
+            }
+        }
+
+        impl PartialEq<Mixed<$T>> for $Rhs {
+            #[inline]
+            fn eq(&self, other: &Mixed<$T>) -> bool {
+                *other == *self
+            }
+        }
+
+        impl PartialEq<Mixed<$Rhs>> for Mixed<$T> {
+            #[inline]
+            fn eq(&self, other: &Mixed<$Rhs>) -> bool {
+                *self == other.0
+            }
+        }
+
+        impl AbsDiffEq<Mixed<$Rhs>> for Mixed<$T> {
+            type Epsilon = $F;
+
+            #[inline]
+            fn default_epsilon() -> $F {
+                $F::default_epsilon()
+            }
+
+            #[inline]
+            fn scale_epsilon(epsilon: &$F, scale: f64) -> Option<$F> {
+                $F::scale_epsilon(epsilon, scale)
+            }
+
+            #[inline]
+            fn epsilon_from_f64(value: f64) -> Option<$F> {
+                $F::epsilon_from_f64(value)
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Mixed<$Rhs>, epsilon: $F) -> bool {
+                self.abs_diff_eq(&other.0, epsilon)
+            }
+        }
+
+        impl RelativeEq<Mixed<$Rhs>> for Mixed<$T> {
+            #[inline]
+            fn default_max_relative() -> $F {
+                $F::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Mixed<$Rhs>, epsilon: $F, max_relative: $F) -> bool {
+                self.relative_eq(&other.0, epsilon, max_relative)
+            }
+        }
+
+        impl UlpsEq<Mixed<$Rhs>> for Mixed<$T> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                $F::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Mixed<$Rhs>, epsilon: $F, max_ulps: u32) -> bool {
+                self.ulps_eq(&other.0, epsilon, max_ulps)
+            }
+        }
+    };
+}
+
+macro_rules! impl_mixed_integers {
+    ($($I:ident),+) => {
+        $(
+            impl_mixed!($I, f32, f32);
+            impl_mixed!($I, f64, f64);
+            impl_mixed!(f32, $I, f32);
+            impl_mixed!(f64, $I, f64);
+        )+
+    };
+}
+
+impl_mixed!(f32, f64, f32);
+impl_mixed!(f64, f32, f32);
+impl_mixed_integers!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
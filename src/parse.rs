@@ -0,0 +1,291 @@
+use core::fmt;
+use core::ops::Range;
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::error::Error;
+use {AbsDiff, AbsDiffEq, Relative, RelativeEq, Ulps, UlpsEq};
+
+/// The reason that a tolerance spec failed to parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseToleranceErrorKind {
+    /// An item was empty, such as in `abs=1e-12,,rel=1e-9` or `abs=1e-12,`.
+    EmptyItem,
+    /// An item did not contain `=`.
+    MissingEquals,
+    /// The key of an item was not recognised.
+    UnknownKey,
+    /// The key of an item appeared more than once.
+    DuplicateKey,
+    /// The value of an item could not be parsed.
+    InvalidValue,
+}
+
+/// An error which can be returned when parsing a tolerance spec.
+///
+/// # Example
+///
+/// ```rust
+/// use approx::{ParseToleranceErrorKind, Relative};
+///
+/// let error = "rel=1e-9, abs=1e-x".parse::<Relative<f64>>().unwrap_err();
+/// assert_eq!(error.kind(), ParseToleranceErrorKind::InvalidValue);
+/// assert_eq!(error.span(), 14..18);
+/// assert_eq!(error.to_string(), "invalid value for `abs` at bytes 14..18");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseToleranceError {
+    kind: ParseToleranceErrorKind,
+    span: Range<usize>,
+    key: Option<&'static str>,
+    keys: &'static [&'static str],
+}
+
+impl ParseToleranceError {
+    /// The reason that the spec failed to parse.
+    #[inline]
+    pub fn kind(&self) -> ParseToleranceErrorKind {
+        self.kind
+    }
+
+    /// The byte range of the spec that caused the error.
+    #[inline]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl fmt::Display for ParseToleranceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ParseToleranceErrorKind::EmptyItem => write!(f, "expected a `key=value` item")?,
+            ParseToleranceErrorKind::MissingEquals => write!(f, "expected `=` after the key")?,
+            ParseToleranceErrorKind::UnknownKey => {
+                write!(f, "unknown key, expected one of ")?;
+                for (i, key) in self.keys.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "`{}`", key)?;
+                }
+            }
+            ParseToleranceErrorKind::DuplicateKey => {
+                write!(f, "duplicate key `{}`", self.key.unwrap_or_default())?
+            }
+            ParseToleranceErrorKind::InvalidValue => {
+                write!(f, "invalid value for `{}`", self.key.unwrap_or_default())?
+            }
+        }
+        write!(f, " at bytes {}..{}", self.span.start, self.span.end)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseToleranceError {}
+
+/// A value of a spec, and the byte range that it was parsed from.
+type Item<'a> = Option<(&'a str, Range<usize>)>;
+
+/// Split `spec` into the values of each of `keys`.
+fn parse_items<'a>(
+    spec: &'a str,
+    keys: &'static [&'static str],
+    values: &mut [Item<'a>],
+) -> Result<(), ParseToleranceError> {
+    let offset = |s: &str| s.as_ptr() as usize - spec.as_ptr() as usize;
+    let error = |kind, span, key| ParseToleranceError {
+        kind,
+        span,
+        key,
+        keys,
+    };
+
+    if spec.trim().is_empty() {
+        return Ok(());
+    }
+
+    for item in spec.split(',') {
+        let trimmed = item.trim();
+        let start = offset(trimmed);
+        if trimmed.is_empty() {
+            return Err(error(
+                ParseToleranceErrorKind::EmptyItem,
+                offset(item)..offset(item) + item.len(),
+                None,
+            ));
+        }
+
+        let equals = match trimmed.find('=') {
+            Some(equals) => equals,
+            None => {
+                return Err(error(
+                    ParseToleranceErrorKind::MissingEquals,
+                    start..start + trimmed.len(),
+                    None,
+                ))
+            }
+        };
+
+        let key = trimmed[..equals].trim_end();
+        let index = match keys.iter().position(|k| *k == key) {
+            Some(index) => index,
+            None => {
+                return Err(error(
+                    ParseToleranceErrorKind::UnknownKey,
+                    start..start + key.len(),
+                    None,
+                ))
+            }
+        };
+        if values[index].is_some() {
+            return Err(error(
+                ParseToleranceErrorKind::DuplicateKey,
+                start..start + key.len(),
+                Some(keys[index]),
+            ));
+        }
+
+        let value = trimmed[equals + 1..].trim_start();
+        let value_start = offset(value);
+        values[index] = Some((value, value_start..value_start + value.len()));
+    }
+
+    Ok(())
+}
+
+/// Parse the value of a key, if it was present in the spec.
+fn parse_value<T: FromStr>(
+    value: Item,
+    key: &'static str,
+    keys: &'static [&'static str],
+) -> Result<Option<T>, ParseToleranceError> {
+    match value {
+        None => Ok(None),
+        Some((value, span)) => match value.parse() {
+            Ok(value) => Ok(Some(value)),
+            Err(_) => Err(ParseToleranceError {
+                kind: ParseToleranceErrorKind::InvalidValue,
+                span,
+                key: Some(key),
+                keys,
+            }),
+        },
+    }
+}
+
+const ABS_DIFF_KEYS: &[&str] = &["abs"];
+const RELATIVE_KEYS: &[&str] = &["abs", "rel"];
+const ULPS_KEYS: &[&str] = &["abs", "ulps"];
+
+/// Parse an absolute difference based comparison from a spec such as `abs=1e-12`.
+///
+/// See the [crate documentation](index.html#tolerance-specs) for the grammar.
+impl<A, B> FromStr for AbsDiff<A, B>
+where
+    A: AbsDiffEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: FromStr,
+{
+    type Err = ParseToleranceError;
+
+    fn from_str(spec: &str) -> Result<AbsDiff<A, B>, ParseToleranceError> {
+        let mut values = [None];
+        parse_items(spec, ABS_DIFF_KEYS, &mut values)?;
+        let [abs] = values;
+
+        let mut comparison = AbsDiff::default();
+        if let Some(epsilon) = parse_value(abs, "abs", ABS_DIFF_KEYS)? {
+            comparison.epsilon = epsilon;
+        }
+        Ok(comparison)
+    }
+}
+
+/// Parse a relative based comparison from a spec such as `rel=1e-9,abs=1e-12`.
+///
+/// See the [crate documentation](index.html#tolerance-specs) for the grammar.
+impl<A, B> FromStr for Relative<A, B>
+where
+    A: RelativeEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: FromStr,
+{
+    type Err = ParseToleranceError;
+
+    fn from_str(spec: &str) -> Result<Relative<A, B>, ParseToleranceError> {
+        let mut values = [None, None];
+        parse_items(spec, RELATIVE_KEYS, &mut values)?;
+        let [abs, rel] = values;
+
+        let mut comparison = Relative::default();
+        if let Some(epsilon) = parse_value(abs, "abs", RELATIVE_KEYS)? {
+            comparison.epsilon = epsilon;
+        }
+        if let Some(max_relative) = parse_value(rel, "rel", RELATIVE_KEYS)? {
+            comparison.max_relative = max_relative;
+        }
+        Ok(comparison)
+    }
+}
+
+/// Parse an ULPs based comparison from a spec such as `ulps=4,abs=1e-12`.
+///
+/// See the [crate documentation](index.html#tolerance-specs) for the grammar.
+impl<A, B> FromStr for Ulps<A, B>
+where
+    A: UlpsEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: FromStr,
+{
+    type Err = ParseToleranceError;
+
+    fn from_str(spec: &str) -> Result<Ulps<A, B>, ParseToleranceError> {
+        let mut values = [None, None];
+        parse_items(spec, ULPS_KEYS, &mut values)?;
+        let [abs, ulps] = values;
+
+        let mut comparison = Ulps::default();
+        if let Some(epsilon) = parse_value(abs, "abs", ULPS_KEYS)? {
+            comparison.epsilon = epsilon;
+        }
+        if let Some(max_ulps) = parse_value(ulps, "ulps", ULPS_KEYS)? {
+            comparison.max_ulps = max_ulps;
+        }
+        Ok(comparison)
+    }
+}
+
+/// Format as a spec such as `abs=2.220446049250313e-16`.
+impl<A, B> fmt::Display for AbsDiff<A, B>
+where
+    A: AbsDiffEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: fmt::LowerExp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "abs={:e}", self.epsilon)
+    }
+}
+
+/// Format as a spec such as `abs=2.220446049250313e-16,rel=1e-9`.
+impl<A, B> fmt::Display for Relative<A, B>
+where
+    A: RelativeEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: fmt::LowerExp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "abs={:e},rel={:e}", self.epsilon, self.max_relative)
+    }
+}
+
+/// Format as a spec such as `abs=2.220446049250313e-16,ulps=4`.
+impl<A, B> fmt::Display for Ulps<A, B>
+where
+    A: UlpsEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: fmt::LowerExp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "abs={:e},ulps={}", self.epsilon, self.max_ulps)
+    }
+}
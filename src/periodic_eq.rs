@@ -0,0 +1,365 @@
+use core::{cell, f32, f64};
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+#[cfg(feature = "num-complex")]
+use num_traits::Float;
+use {AbsDiffEq, RelativeEq, UlpsEq};
+
+/// Equality comparisons between periodic quantities, such as angles or phases, using the
+/// shortest distance around the period.
+///
+/// For two numbers `a` and `b`, `b` is first shifted by a whole number of periods so that it is
+/// as close as possible to `a`. The two numbers are then compared using the [`AbsDiffEq`],
+/// [`RelativeEq`] or [`UlpsEq`] implementation of the underlying type.
+///
+/// `angle_eq`, `angle_ne`, `assert_angle_eq`, and `assert_angle_ne` macros are all wrappers of
+/// the functions in this trait.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate approx;
+/// # use std::f64::consts::PI;
+/// # fn main() {
+/// assert_angle_eq!(PI, -PI);
+/// assert_angle_eq!(0.0f64, 2.0 * PI);
+/// assert_angle_eq!(359.9f64, -0.1f64, period = 360.0, epsilon = 1e-9);
+/// assert_angle_ne!(0.0f64, PI);
+/// # }
+/// ```
+pub trait PeriodicEq<Rhs = Self>: AbsDiffEq<Rhs>
+where
+    Rhs: ?Sized,
+{
+    /// The default period, used when no `period` value is supplied to the `angle_eq!` macro.
+    ///
+    /// For floating point numbers this is a full turn in radians, `2π`.
+    fn default_period() -> Self::Epsilon;
+
+    /// A test for equality that uses the absolute difference of the shortest distance between
+    /// the two values.
+    fn periodic_abs_diff_eq(
+        &self,
+        other: &Rhs,
+        period: Self::Epsilon,
+        epsilon: Self::Epsilon,
+    ) -> bool;
+
+    /// A test for equality that uses a relative comparison of the two values once they have been
+    /// brought within half a period of each other.
+    fn periodic_relative_eq(
+        &self,
+        other: &Rhs,
+        period: Self::Epsilon,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool;
+
+    /// A test for equality that uses units in the last place (ULP) once the two values have been
+    /// brought within half a period of each other.
+    fn periodic_ulps_eq(
+        &self,
+        other: &Rhs,
+        period: Self::Epsilon,
+        epsilon: Self::Epsilon,
+        max_ulps: u32,
+    ) -> bool;
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Base implementations
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+// The signed distance from `$lhs` to the representative of `$rhs` that is nearest to `$lhs`,
+// lying in the range `[-period / 2, period / 2]`.
+macro_rules! wrapped_distance {
+    ($T:ident, $lhs:expr, $rhs:expr, $period:expr) => {{
+        let period: $T = $period;
+        let half_period = period / 2.0;
+        let distance = ($rhs - $lhs) % period;
+        if distance > half_period {
+            distance - period
+        } else if distance < -half_period {
+            distance + period
+        } else {
+            distance
+        }
+    }};
+}
+
+macro_rules! impl_periodic_eq {
+    ($T:ident) => {
+        impl PeriodicEq for $T {
+            #[inline]
+            fn default_period() -> $T {
+                2.0 * $T::consts::PI
+            }
+
+            #[inline]
+            #[allow(unused_imports)]
+            fn periodic_abs_diff_eq(&self, other: &$T, period: $T, epsilon: $T) -> bool {
+                use num_traits::float::FloatCore;
+                $T::abs(wrapped_distance!($T, *self, *other, period)) <= epsilon
+            }
+
+            #[inline]
+            fn periodic_relative_eq(
+                &self,
+                other: &$T,
+                period: $T,
+                epsilon: $T,
+                max_relative: $T,
+            ) -> bool {
+                let other = *self + wrapped_distance!($T, *self, *other, period);
+                $T::relative_eq(self, &other, epsilon, max_relative)
+            }
+
+            #[inline]
+            fn periodic_ulps_eq(&self, other: &$T, period: $T, epsilon: $T, max_ulps: u32) -> bool {
+                let other = *self + wrapped_distance!($T, *self, *other, period);
+                $T::ulps_eq(self, &other, epsilon, max_ulps)
+            }
+        }
+    };
+}
+
+impl_periodic_eq!(f32);
+impl_periodic_eq!(f64);
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Derived implementations
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'a, T: PeriodicEq + ?Sized> PeriodicEq for &'a T {
+    #[inline]
+    fn default_period() -> T::Epsilon {
+        T::default_period()
+    }
+
+    #[inline]
+    fn periodic_abs_diff_eq(&self, other: &&'a T, period: T::Epsilon, epsilon: T::Epsilon) -> bool {
+        T::periodic_abs_diff_eq(*self, *other, period, epsilon)
+    }
+
+    #[inline]
+    fn periodic_relative_eq(
+        &self,
+        other: &&'a T,
+        period: T::Epsilon,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+    ) -> bool {
+        T::periodic_relative_eq(*self, *other, period, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn periodic_ulps_eq(
+        &self,
+        other: &&'a T,
+        period: T::Epsilon,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+    ) -> bool {
+        T::periodic_ulps_eq(*self, *other, period, epsilon, max_ulps)
+    }
+}
+
+impl<'a, T: PeriodicEq + ?Sized> PeriodicEq for &'a mut T {
+    #[inline]
+    fn default_period() -> T::Epsilon {
+        T::default_period()
+    }
+
+    #[inline]
+    fn periodic_abs_diff_eq(
+        &self,
+        other: &&'a mut T,
+        period: T::Epsilon,
+        epsilon: T::Epsilon,
+    ) -> bool {
+        T::periodic_abs_diff_eq(*self, *other, period, epsilon)
+    }
+
+    #[inline]
+    fn periodic_relative_eq(
+        &self,
+        other: &&'a mut T,
+        period: T::Epsilon,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+    ) -> bool {
+        T::periodic_relative_eq(*self, *other, period, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn periodic_ulps_eq(
+        &self,
+        other: &&'a mut T,
+        period: T::Epsilon,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+    ) -> bool {
+        T::periodic_ulps_eq(*self, *other, period, epsilon, max_ulps)
+    }
+}
+
+impl<T: PeriodicEq + Copy> PeriodicEq for cell::Cell<T> {
+    #[inline]
+    fn default_period() -> T::Epsilon {
+        T::default_period()
+    }
+
+    #[inline]
+    fn periodic_abs_diff_eq(
+        &self,
+        other: &cell::Cell<T>,
+        period: T::Epsilon,
+        epsilon: T::Epsilon,
+    ) -> bool {
+        T::periodic_abs_diff_eq(&self.get(), &other.get(), period, epsilon)
+    }
+
+    #[inline]
+    fn periodic_relative_eq(
+        &self,
+        other: &cell::Cell<T>,
+        period: T::Epsilon,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+    ) -> bool {
+        T::periodic_relative_eq(&self.get(), &other.get(), period, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn periodic_ulps_eq(
+        &self,
+        other: &cell::Cell<T>,
+        period: T::Epsilon,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+    ) -> bool {
+        T::periodic_ulps_eq(&self.get(), &other.get(), period, epsilon, max_ulps)
+    }
+}
+
+impl<T: PeriodicEq + ?Sized> PeriodicEq for cell::RefCell<T> {
+    #[inline]
+    fn default_period() -> T::Epsilon {
+        T::default_period()
+    }
+
+    #[inline]
+    fn periodic_abs_diff_eq(
+        &self,
+        other: &cell::RefCell<T>,
+        period: T::Epsilon,
+        epsilon: T::Epsilon,
+    ) -> bool {
+        T::periodic_abs_diff_eq(&self.borrow(), &other.borrow(), period, epsilon)
+    }
+
+    #[inline]
+    fn periodic_relative_eq(
+        &self,
+        other: &cell::RefCell<T>,
+        period: T::Epsilon,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+    ) -> bool {
+        T::periodic_relative_eq(
+            &self.borrow(),
+            &other.borrow(),
+            period,
+            epsilon,
+            max_relative,
+        )
+    }
+
+    #[inline]
+    fn periodic_ulps_eq(
+        &self,
+        other: &cell::RefCell<T>,
+        period: T::Epsilon,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+    ) -> bool {
+        T::periodic_ulps_eq(&self.borrow(), &other.borrow(), period, epsilon, max_ulps)
+    }
+}
+
+impl<A, B> PeriodicEq<[B]> for [A]
+where
+    A: PeriodicEq<B>,
+    A::Epsilon: Clone,
+{
+    #[inline]
+    fn default_period() -> A::Epsilon {
+        A::default_period()
+    }
+
+    #[inline]
+    fn periodic_abs_diff_eq(&self, other: &[B], period: A::Epsilon, epsilon: A::Epsilon) -> bool {
+        self.len() == other.len()
+            && Iterator::zip(self.iter(), other)
+                .all(|(x, y)| A::periodic_abs_diff_eq(x, y, period.clone(), epsilon.clone()))
+    }
+
+    #[inline]
+    fn periodic_relative_eq(
+        &self,
+        other: &[B],
+        period: A::Epsilon,
+        epsilon: A::Epsilon,
+        max_relative: A::Epsilon,
+    ) -> bool {
+        self.len() == other.len()
+            && Iterator::zip(self.iter(), other).all(|(x, y)| {
+                A::periodic_relative_eq(x, y, period.clone(), epsilon.clone(), max_relative.clone())
+            })
+    }
+
+    #[inline]
+    fn periodic_ulps_eq(
+        &self,
+        other: &[B],
+        period: A::Epsilon,
+        epsilon: A::Epsilon,
+        max_ulps: u32,
+    ) -> bool {
+        self.len() == other.len()
+            && Iterator::zip(self.iter(), other)
+                .all(|(x, y)| A::periodic_ulps_eq(x, y, period.clone(), epsilon.clone(), max_ulps))
+    }
+}
+
+/// Compares the phases (arguments) of two complex numbers, in radians.
+///
+/// The magnitudes of the numbers are ignored.
+#[cfg(feature = "num-complex")]
+impl<T: PeriodicEq<Epsilon = T> + Float> PeriodicEq for Complex<T> {
+    #[inline]
+    fn default_period() -> T {
+        T::default_period()
+    }
+
+    #[inline]
+    fn periodic_abs_diff_eq(&self, other: &Complex<T>, period: T, epsilon: T) -> bool {
+        T::periodic_abs_diff_eq(&self.arg(), &other.arg(), period, epsilon)
+    }
+
+    #[inline]
+    fn periodic_relative_eq(
+        &self,
+        other: &Complex<T>,
+        period: T,
+        epsilon: T,
+        max_relative: T,
+    ) -> bool {
+        T::periodic_relative_eq(&self.arg(), &other.arg(), period, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn periodic_ulps_eq(&self, other: &Complex<T>, period: T, epsilon: T, max_ulps: u32) -> bool {
+        T::periodic_ulps_eq(&self.arg(), &other.arg(), period, epsilon, max_ulps)
+    }
+}
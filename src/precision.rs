@@ -0,0 +1,136 @@
+use num_traits::Float;
+
+/// The requisite parameters for testing that two numbers agree to a number of significant
+/// figures.
+///
+/// Two numbers agree to `n` significant figures if their difference is at most half a unit in
+/// the `n`th significant figure of the larger of the two in magnitude.
+///
+/// This is not normally used directly, rather via the `assert_sig_figs_{eq|ne}!` and
+/// `sig_figs_{eq|ne}!` macros.
+///
+/// # Example
+///
+/// ```rust
+/// use approx::SigFigs;
+///
+/// assert!(SigFigs::new(3).eq(&1234.0, &1231.0));
+/// assert!(SigFigs::new(3).ne(&1234.0, &1226.0));
+/// assert!(SigFigs::new(3).eq(&0.0001234, &0.0001231));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SigFigs {
+    /// The number of significant figures that must agree.
+    pub sig_figs: u32,
+}
+
+impl SigFigs {
+    /// Compare to the number of significant figures specified.
+    #[inline]
+    pub fn new(sig_figs: u32) -> SigFigs {
+        SigFigs { sig_figs }
+    }
+
+    /// Peform the equality comparison
+    #[must_use]
+    pub fn eq<T: Float>(&self, lhs: &T, rhs: &T) -> bool {
+        let (lhs, rhs) = (*lhs, *rhs);
+
+        // Handle same infinities and zeros
+        if lhs == rhs {
+            return true;
+        }
+
+        // Handle remaining infinities
+        if !lhs.is_finite() || !rhs.is_finite() {
+            return false;
+        }
+
+        (lhs - rhs).abs() <= self.tolerance(&lhs, &rhs)
+    }
+
+    /// Peform the inequality comparison
+    #[must_use]
+    pub fn ne<T: Float>(&self, lhs: &T, rhs: &T) -> bool {
+        !self.eq(lhs, rhs)
+    }
+
+    /// The largest difference allowed between the numbers, which is half a unit in the last
+    /// significant figure of the larger of the two in magnitude.
+    pub fn tolerance<T: Float>(&self, lhs: &T, rhs: &T) -> T {
+        let largest = lhs.abs().max(rhs.abs());
+        let magnitude = largest.log10().floor();
+        let unit = ten::<T>().powf(magnitude - T::from(self.sig_figs).unwrap() + T::one());
+
+        unit / (T::one() + T::one())
+    }
+}
+
+/// The requisite parameters for testing that two numbers agree to a number of decimal places.
+///
+/// Two numbers agree to `n` decimal places if their difference is at most half a unit in the
+/// `n`th decimal place, like `round(a - b, n) == 0` in Python's `assertAlmostEqual`.
+///
+/// This is not normally used directly, rather via the `assert_places_{eq|ne}!` and
+/// `places_{eq|ne}!` macros.
+///
+/// # Example
+///
+/// ```rust
+/// use approx::DecimalPlaces;
+///
+/// assert!(DecimalPlaces::new(2).eq(&1.234, &1.231));
+/// assert!(DecimalPlaces::new(2).ne(&1.234, &1.226));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecimalPlaces {
+    /// The number of decimal places that must agree.
+    pub places: u32,
+}
+
+impl DecimalPlaces {
+    /// Compare to the number of decimal places specified.
+    #[inline]
+    pub fn new(places: u32) -> DecimalPlaces {
+        DecimalPlaces { places }
+    }
+
+    /// Peform the equality comparison
+    #[must_use]
+    pub fn eq<T: Float>(&self, lhs: &T, rhs: &T) -> bool {
+        let (lhs, rhs) = (*lhs, *rhs);
+
+        // Handle same infinities
+        if lhs == rhs {
+            return true;
+        }
+
+        (lhs - rhs).abs() <= self.tolerance()
+    }
+
+    /// Peform the inequality comparison
+    #[must_use]
+    pub fn ne<T: Float>(&self, lhs: &T, rhs: &T) -> bool {
+        !self.eq(lhs, rhs)
+    }
+
+    /// The largest difference allowed between the numbers, which is half a unit in the last
+    /// decimal place.
+    pub fn tolerance<T: Float>(&self) -> T {
+        let unit = ten::<T>().powf(-T::from(self.places).unwrap());
+
+        unit / (T::one() + T::one())
+    }
+}
+
+/// Used by the `assert_places_{eq|ne}!` macros to infer the type of the tolerance from the
+/// compared values.
+#[doc(hidden)]
+#[inline]
+pub fn __places_tolerance<T: Float>(places: u32, _: &T) -> T {
+    DecimalPlaces::new(places).tolerance()
+}
+
+fn ten<T: Float>() -> T {
+    T::from(10).unwrap()
+}
@@ -0,0 +1,218 @@
+use core::fmt;
+use core::marker::PhantomData;
+use defaults;
+use {AbsDiff, AbsDiffEq, Relative, RelativeEq, Ulps, UlpsEq};
+
+/// A set of default tolerances, for use in place of the defaults of the compared types.
+///
+/// The tolerances are defined for an epsilon type `E`, so a profile implemented for `f64` applies
+/// to every type whose `Epsilon` is `f64`, such as `[f64]` or `Complex<f64>`.
+///
+/// A profile is used either by wrapping the compared values in [`WithProfile`], which allows it
+/// to be used with the standard macros, or by constructing a comparison with its tolerances using
+/// `with_profile`.
+///
+/// The comparisons do not take the profile as a type parameter, as in
+/// `Relative::<f64, Geometry>::default()`, because their second type parameter is already the
+/// type of the right hand side, as in `Relative::<Mixed<f64>, f32>`. The equivalents are
+/// `Relative::<f64>::with_profile::<Geometry>()`, or
+/// `Relative::<WithProfile<f64, Geometry>>::default()` when comparing wrapped values.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+///
+/// use approx::{Relative, ToleranceProfile, WithProfile};
+///
+/// struct Geometry;
+///
+/// impl ToleranceProfile<f64> for Geometry {
+///     fn default_epsilon() -> f64 { 1e-9 }
+///     fn default_max_relative() -> f64 { 1e-9 }
+///     fn default_max_ulps() -> u32 { 16 }
+/// }
+///
+/// type Length = WithProfile<f64, Geometry>;
+///
+/// # fn main() {
+/// assert_relative_eq!(Length::new(1.0), Length::new(1.0 + 1e-10));
+/// assert_relative_ne!(1.0, 1.0 + 1e-10);
+///
+/// assert!(Relative::<f64>::with_profile::<Geometry>().eq(&0.0, &1e-10));
+/// assert!(Relative::<Length>::default().ne(&Length::new(0.0), &Length::new(1e-8)));
+/// # }
+/// ```
+pub trait ToleranceProfile<E> {
+    /// The default tolerance to use when testing values that are close together.
+    fn default_epsilon() -> E;
+
+    /// The default relative tolerance for testing values that are far-apart.
+    fn default_max_relative() -> E;
+
+    /// The default ULPs to tolerate when testing values that are far-apart.
+    fn default_max_ulps() -> u32;
+}
+
+/// A value that is compared using the default tolerances of the profile `P`, rather than those of
+/// `T`.
+///
+/// The `Debug` output is that of the wrapped value, so the failure messages of the assertion
+/// macros are unchanged.
+pub struct WithProfile<T, P> {
+    /// The wrapped value.
+    pub value: T,
+    profile: PhantomData<fn() -> P>,
+}
+
+impl<T, P> WithProfile<T, P> {
+    /// Wrap a value to be compared using the defaults of the profile `P`.
+    #[inline]
+    pub fn new(value: T) -> WithProfile<T, P> {
+        WithProfile {
+            value,
+            profile: PhantomData,
+        }
+    }
+
+    /// Unwrap the value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: Clone, P> Clone for WithProfile<T, P> {
+    #[inline]
+    fn clone(&self) -> WithProfile<T, P> {
+        WithProfile::new(self.value.clone())
+    }
+}
+
+impl<T: Copy, P> Copy for WithProfile<T, P> {}
+
+impl<T: fmt::Debug, P> fmt::Debug for WithProfile<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T, U, P> PartialEq<WithProfile<U, P>> for WithProfile<T, P>
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &WithProfile<U, P>) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T, U, P> AbsDiffEq<WithProfile<U, P>> for WithProfile<T, P>
+where
+    T: AbsDiffEq<U>,
+    P: ToleranceProfile<T::Epsilon>,
+{
+    type Epsilon = T::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> T::Epsilon {
+        P::default_epsilon()
+    }
+
+    #[inline]
+    fn scale_epsilon(epsilon: &T::Epsilon, scale: f64) -> Option<T::Epsilon> {
+        T::scale_epsilon(epsilon, scale)
+    }
+
+    #[inline]
+    fn epsilon_from_f64(value: f64) -> Option<T::Epsilon> {
+        T::epsilon_from_f64(value)
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &WithProfile<U, P>, epsilon: T::Epsilon) -> bool {
+        T::abs_diff_eq(&self.value, &other.value, epsilon)
+    }
+}
+
+impl<T, U, P> RelativeEq<WithProfile<U, P>> for WithProfile<T, P>
+where
+    T: RelativeEq<U>,
+    P: ToleranceProfile<T::Epsilon>,
+{
+    #[inline]
+    fn default_max_relative() -> T::Epsilon {
+        P::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &WithProfile<U, P>,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+    ) -> bool {
+        T::relative_eq(&self.value, &other.value, epsilon, max_relative)
+    }
+}
+
+impl<T, U, P> UlpsEq<WithProfile<U, P>> for WithProfile<T, P>
+where
+    T: UlpsEq<U>,
+    P: ToleranceProfile<T::Epsilon>,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        P::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &WithProfile<U, P>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        T::ulps_eq(&self.value, &other.value, epsilon, max_ulps)
+    }
+}
+
+impl<A, B> AbsDiff<A, B>
+where
+    A: AbsDiffEq<B> + ?Sized,
+    B: ?Sized,
+{
+    /// Compare using the default tolerance of the profile `P`.
+    #[inline]
+    pub fn with_profile<P: ToleranceProfile<A::Epsilon>>() -> AbsDiff<A, B> {
+        AbsDiff {
+            epsilon: defaults::epsilon::<A, B>(P::default_epsilon()),
+        }
+    }
+}
+
+impl<A, B> Relative<A, B>
+where
+    A: RelativeEq<B> + ?Sized,
+    B: ?Sized,
+{
+    /// Compare using the default tolerances of the profile `P`.
+    #[inline]
+    pub fn with_profile<P: ToleranceProfile<A::Epsilon>>() -> Relative<A, B> {
+        Relative {
+            epsilon: defaults::epsilon::<A, B>(P::default_epsilon()),
+            max_relative: defaults::max_relative::<A, B>(P::default_max_relative()),
+        }
+    }
+}
+
+impl<A, B> Ulps<A, B>
+where
+    A: UlpsEq<B> + ?Sized,
+    B: ?Sized,
+{
+    /// Compare using the default tolerances of the profile `P`.
+    #[inline]
+    pub fn with_profile<P: ToleranceProfile<A::Epsilon>>() -> Ulps<A, B> {
+        Ulps {
+            epsilon: defaults::epsilon::<A, B>(P::default_epsilon()),
+            max_ulps: defaults::max_ulps(P::default_max_ulps()),
+        }
+    }
+}
@@ -5,6 +5,8 @@ use num_complex::Complex;
 use num_traits::Float;
 #[cfg(feature = "ordered-float")]
 use ordered_float::{NotNan, OrderedFloat};
+#[cfg(feature = "std")]
+use std::string::String;
 use AbsDiffEq;
 
 /// Equality comparisons between two numbers using both the absolute difference and
@@ -104,6 +106,26 @@ macro_rules! impl_relative_eq {
 impl_relative_eq!(f32, i32);
 impl_relative_eq!(f64, i64);
 
+macro_rules! impl_exact_relative_eq {
+    ($T:ty) => {
+        impl RelativeEq for $T {
+            #[inline]
+            fn default_max_relative() {}
+
+            #[inline]
+            fn relative_eq(&self, other: &$T, _: (), _: ()) -> bool {
+                self == other
+            }
+        }
+    };
+}
+
+impl_exact_relative_eq!(bool);
+impl_exact_relative_eq!(char);
+impl_exact_relative_eq!(str);
+#[cfg(feature = "std")]
+impl_exact_relative_eq!(String);
+
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // Derived implementations
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,249 @@
+use num_traits::float::FloatCore;
+use num_traits::Zero;
+use {Relative, RelativeEq, Ulps, UlpsEq};
+
+/// The requisite parameters for testing for approximate equality using a relative based
+/// comparison, where the tolerance is relative to a fixed magnitude rather than the operands.
+///
+/// Values are considered equal if their absolute difference is at most the greater of `epsilon`
+/// and `scale * max_relative`. This is useful when one of the operands is expected to be zero,
+/// such as the residual of a computation, where `scale` is the magnitude of the values the
+/// residual was computed from.
+///
+/// This is not normally used directly, rather via the `scale` option of the
+/// `assert_relative_{eq|ne}!`, `relative_{eq|ne}!` and `assert_approx_zero!` macros.
+///
+/// # Example
+///
+/// ```rust
+/// use approx::Relative;
+///
+/// let (a, b) = (1e10f64, 1e10 + 1e-3);
+/// assert!(Relative::default().max_relative(1e-12).scale(a).eq(&(a - b), &0.0));
+/// assert!(Relative::default().max_relative(1e-12).ne(&(a - b), &0.0));
+/// ```
+pub struct ScaledRelative<A, B = A>
+where
+    A: RelativeEq<B> + ?Sized,
+    B: ?Sized,
+{
+    /// The tolerance to use when testing values that are close together.
+    pub epsilon: A::Epsilon,
+    /// The tolerance relative to `scale`.
+    pub max_relative: A::Epsilon,
+    /// The magnitude that the tolerance is relative to.
+    pub scale: A::Epsilon,
+}
+
+impl<A, B> Relative<A, B>
+where
+    A: RelativeEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: FloatCore,
+{
+    /// Make the tolerance relative to the magnitude specified, rather than to the operands.
+    #[inline]
+    pub fn scale(self, scale: A::Epsilon) -> ScaledRelative<A, B> {
+        ScaledRelative {
+            epsilon: self.epsilon,
+            max_relative: self.max_relative,
+            scale,
+        }
+    }
+}
+
+impl<A, B> ScaledRelative<A, B>
+where
+    A: RelativeEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: FloatCore,
+{
+    /// Replace the epsilon value with the one specified.
+    #[inline]
+    pub fn epsilon(self, epsilon: A::Epsilon) -> ScaledRelative<A, B> {
+        ScaledRelative { epsilon, ..self }
+    }
+
+    /// Replace the maximum relative value with the one specified.
+    #[inline]
+    pub fn max_relative(self, max_relative: A::Epsilon) -> ScaledRelative<A, B> {
+        ScaledRelative {
+            max_relative,
+            ..self
+        }
+    }
+
+    /// Replace the scale with the one specified.
+    #[inline]
+    pub fn scale(self, scale: A::Epsilon) -> ScaledRelative<A, B> {
+        ScaledRelative { scale, ..self }
+    }
+
+    /// The absolute tolerance that the comparison uses.
+    #[inline]
+    pub fn tolerance(&self) -> A::Epsilon {
+        self.epsilon.max(self.scale.abs() * self.max_relative)
+    }
+
+    /// Peform the equality comparison
+    #[inline]
+    #[must_use]
+    pub fn eq(self, lhs: &A, rhs: &B) -> bool {
+        A::abs_diff_eq(lhs, rhs, self.tolerance())
+    }
+
+    /// Peform the inequality comparison
+    #[inline]
+    #[must_use]
+    pub fn ne(self, lhs: &A, rhs: &B) -> bool {
+        A::abs_diff_ne(lhs, rhs, self.tolerance())
+    }
+}
+
+/// The requisite parameters for testing for approximate equality using an ULPs based
+/// comparison, where the ULPs are measured at a fixed magnitude rather than at the operands.
+///
+/// Values are considered equal if their absolute difference is at most the greater of `epsilon`
+/// and `max_ulps` units in the last place of `scale`.
+///
+/// This is not normally used directly, rather via the `scale` option of the
+/// `assert_ulps_{eq|ne}!` and `ulps_{eq|ne}!` macros.
+///
+/// # Example
+///
+/// ```rust
+/// use approx::Ulps;
+///
+/// let (a, b) = (1e10f64, 1e10 + 2e-6);
+/// assert!(Ulps::default().scale(a).eq(&(a - b), &0.0));
+/// assert!(Ulps::default().ne(&(a - b), &0.0));
+/// ```
+pub struct ScaledUlps<A, B = A>
+where
+    A: UlpsEq<B> + ?Sized,
+    B: ?Sized,
+{
+    /// The tolerance to use when testing values that are close together.
+    pub epsilon: A::Epsilon,
+    /// The ULPs of `scale` to tolerate.
+    pub max_ulps: u32,
+    /// The magnitude at which the ULPs are measured.
+    pub scale: A::Epsilon,
+}
+
+impl<A, B> Ulps<A, B>
+where
+    A: UlpsEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: FloatCore,
+{
+    /// Measure the ULPs at the magnitude specified, rather than at the operands.
+    #[inline]
+    pub fn scale(self, scale: A::Epsilon) -> ScaledUlps<A, B> {
+        ScaledUlps {
+            epsilon: self.epsilon,
+            max_ulps: self.max_ulps,
+            scale,
+        }
+    }
+}
+
+impl<A, B> ScaledUlps<A, B>
+where
+    A: UlpsEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: FloatCore,
+{
+    /// Replace the epsilon value with the one specified.
+    #[inline]
+    pub fn epsilon(self, epsilon: A::Epsilon) -> ScaledUlps<A, B> {
+        ScaledUlps { epsilon, ..self }
+    }
+
+    /// Replace the max ulps value with the one specified.
+    #[inline]
+    pub fn max_ulps(self, max_ulps: u32) -> ScaledUlps<A, B> {
+        ScaledUlps { max_ulps, ..self }
+    }
+
+    /// Replace the scale with the one specified.
+    #[inline]
+    pub fn scale(self, scale: A::Epsilon) -> ScaledUlps<A, B> {
+        ScaledUlps { scale, ..self }
+    }
+
+    /// The absolute tolerance that the comparison uses.
+    #[inline]
+    pub fn tolerance(&self) -> A::Epsilon {
+        let max_ulps: A::Epsilon = num_traits::cast(self.max_ulps).unwrap();
+        self.epsilon.max(ulp(self.scale) * max_ulps)
+    }
+
+    /// Peform the equality comparison
+    #[inline]
+    #[must_use]
+    pub fn eq(self, lhs: &A, rhs: &B) -> bool {
+        A::abs_diff_eq(lhs, rhs, self.tolerance())
+    }
+
+    /// Peform the inequality comparison
+    #[inline]
+    #[must_use]
+    pub fn ne(self, lhs: &A, rhs: &B) -> bool {
+        A::abs_diff_ne(lhs, rhs, self.tolerance())
+    }
+}
+
+/// The distance from `x` to the next float away from zero, or zero for zero.
+fn ulp<T: FloatCore>(x: T) -> T {
+    if !x.is_finite() {
+        return x.abs();
+    }
+    // `x = mantissa * 2^exponent` where a unit in the mantissa is one ULP
+    let (mantissa, _, _) = x.integer_decode();
+    if mantissa == 0 {
+        return T::zero();
+    }
+    x.abs() / num_traits::cast(mantissa).unwrap()
+}
+
+impl<T> Relative<T>
+where
+    T: RelativeEq + Zero,
+{
+    /// Peform the equality comparison against zero.
+    ///
+    /// Without a `scale` this is an absolute comparison against `epsilon`.
+    #[inline]
+    #[must_use]
+    pub fn eq_zero(self, value: &T) -> bool {
+        self.eq(value, &T::zero())
+    }
+
+    /// Peform the inequality comparison against zero.
+    #[inline]
+    #[must_use]
+    pub fn ne_zero(self, value: &T) -> bool {
+        self.ne(value, &T::zero())
+    }
+}
+
+impl<T> ScaledRelative<T>
+where
+    T: RelativeEq + Zero,
+    T::Epsilon: FloatCore,
+{
+    /// Peform the equality comparison against zero.
+    #[inline]
+    #[must_use]
+    pub fn eq_zero(self, value: &T) -> bool {
+        self.eq(value, &T::zero())
+    }
+
+    /// Peform the inequality comparison against zero.
+    #[inline]
+    #[must_use]
+    pub fn ne_zero(self, value: &T) -> bool {
+        self.ne(value, &T::zero())
+    }
+}
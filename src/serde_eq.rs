@@ -0,0 +1,618 @@
+use core::cmp::Ordering;
+use core::fmt;
+use serde::ser::{self, Serialize};
+use std::boxed::Box;
+use std::collections::BTreeMap;
+use std::string::{String, ToString};
+use std::vec::Vec;
+use {FieldMismatch, Tolerance};
+
+/// Compare two values by their serialized structure, returning the differences between them.
+///
+/// Both values are serialized into a tree, and the trees are compared leaf by leaf:
+///
+/// - pairs of floats are compared using the tolerance, with `f32` values using the tolerance
+///   converted to `f32`, so the default `f64` tolerances are stricter than `f32` precision
+/// - integers compared with floats are converted to `f64` and compared using the tolerance
+/// - pairs of integers are compared by value, whatever their signedness
+/// - all other leaves are compared exactly
+///
+/// Structs and maps are compared by name or key, and sequences by index. The mismatches are
+/// returned with paths such as `.results[4].energy`, or `.` for the values themselves, and are
+/// empty if the values are equal.
+///
+/// This allows values whose types only implement `Serialize`, such as those of other crates, to
+/// be compared without implementing the approximate equality traits for them.
+///
+/// # Errors
+///
+/// If either value fails to serialize.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate serde;
+/// extern crate approx;
+///
+/// use approx::Relative;
+///
+/// #[derive(Serialize)]
+/// struct Sample {
+///     name: &'static str,
+///     energy: f64,
+/// }
+///
+/// # fn main() {
+/// let a = vec![Sample { name: "a", energy: 1.0 }, Sample { name: "b", energy: 2.0 }];
+/// let b = vec![Sample { name: "a", energy: 1.0 + 1e-12 }, Sample { name: "c", energy: 2.0 }];
+///
+/// let tolerance = Relative::<f64>::default().epsilon(1e-9).into();
+/// let mismatches = approx::serde_approx_eq(&a, &b, &tolerance).unwrap();
+/// assert_eq!(mismatches.len(), 1);
+/// assert_eq!(mismatches[0].to_string(), r#"[1].name: left = "b", right = "c""#);
+/// # }
+/// ```
+pub fn serde_approx_eq<A, B>(
+    lhs: &A,
+    rhs: &B,
+    tolerance: &Tolerance<f64>,
+) -> Result<Vec<FieldMismatch>, SerializeError>
+where
+    A: Serialize + ?Sized,
+    B: Serialize + ?Sized,
+{
+    let lhs = lhs.serialize(TreeSerializer)?;
+    let rhs = rhs.serialize(TreeSerializer)?;
+
+    let mut diff = Diff {
+        tolerance,
+        tolerance_f32: tolerance.try_map(&|&epsilon| Some(epsilon as f32)).unwrap(),
+        path: String::new(),
+        mismatches: Vec::new(),
+    };
+    diff.compare(&lhs, &rhs);
+    Ok(diff.mismatches)
+}
+
+/// An error returned by [`serde_approx_eq`] when a value fails to serialize.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerializeError {
+    message: String,
+}
+
+impl fmt::Display for SerializeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "failed to serialize value: {}", self.message)
+    }
+}
+
+impl ser::StdError for SerializeError {}
+
+impl ser::Error for SerializeError {
+    fn custom<T: fmt::Display>(message: T) -> SerializeError {
+        SerializeError {
+            message: message.to_string(),
+        }
+    }
+}
+
+/// A serialized value.
+enum Node {
+    Unit,
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    F32(f32),
+    F64(f64),
+    Char(char),
+    Str(String),
+    Bytes(Vec<u8>),
+    None,
+    Some(Box<Node>),
+    Seq(Vec<Node>),
+    Map(Vec<(Node, Node)>),
+    Struct(&'static str, Vec<(&'static str, Node)>),
+    Variant(&'static str, Box<Node>),
+}
+
+impl Node {
+    fn as_f64(&self) -> Option<f64> {
+        match *self {
+            Node::I64(value) => Some(value as f64),
+            Node::U64(value) => Some(value as f64),
+            Node::F32(value) => Some(f64::from(value)),
+            Node::F64(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The position of the kind of node in the order of nodes, with integers of either
+    /// signedness ordered together.
+    fn rank(&self) -> u8 {
+        match *self {
+            Node::Unit => 0,
+            Node::Bool(_) => 1,
+            Node::I64(_) | Node::U64(_) => 2,
+            Node::F32(_) => 3,
+            Node::F64(_) => 4,
+            Node::Char(_) => 5,
+            Node::Str(_) => 6,
+            Node::Bytes(_) => 7,
+            Node::None => 8,
+            Node::Some(_) => 9,
+            Node::Seq(_) => 10,
+            Node::Map(_) => 11,
+            Node::Struct(..) => 12,
+            Node::Variant(..) => 13,
+        }
+    }
+}
+
+/// Nodes are ordered, and compared exactly, as is done for the keys of maps. Integers are
+/// compared by value whatever their signedness, and floats by their bits.
+impl Ord for Node {
+    fn cmp(&self, other: &Node) -> Ordering {
+        match (self, other) {
+            (Node::Bool(a), Node::Bool(b)) => a.cmp(b),
+            (Node::I64(a), Node::I64(b)) => a.cmp(b),
+            (Node::U64(a), Node::U64(b)) => a.cmp(b),
+            (Node::I64(a), Node::U64(b)) => i128::from(*a).cmp(&i128::from(*b)),
+            (Node::U64(a), Node::I64(b)) => i128::from(*a).cmp(&i128::from(*b)),
+            (Node::F32(a), Node::F32(b)) => a.to_bits().cmp(&b.to_bits()),
+            (Node::F64(a), Node::F64(b)) => a.to_bits().cmp(&b.to_bits()),
+            (Node::Char(a), Node::Char(b)) => a.cmp(b),
+            (Node::Str(a), Node::Str(b)) => a.cmp(b),
+            (Node::Bytes(a), Node::Bytes(b)) => a.cmp(b),
+            (Node::Some(a), Node::Some(b)) => a.cmp(b),
+            (Node::Seq(a), Node::Seq(b)) => a.cmp(b),
+            (Node::Map(a), Node::Map(b)) => a.cmp(b),
+            (Node::Struct(x, a), Node::Struct(y, b)) => (x, a).cmp(&(y, b)),
+            (Node::Variant(x, a), Node::Variant(y, b)) => (x, a).cmp(&(y, b)),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Node) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Node) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Node {}
+
+impl fmt::Debug for Node {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Node::Unit => f.write_str("()"),
+            Node::Bool(value) => value.fmt(f),
+            Node::I64(value) => value.fmt(f),
+            Node::U64(value) => value.fmt(f),
+            Node::F32(value) => value.fmt(f),
+            Node::F64(value) => value.fmt(f),
+            Node::Char(value) => value.fmt(f),
+            Node::Str(ref value) => value.fmt(f),
+            Node::Bytes(ref value) => value.fmt(f),
+            Node::None => f.write_str("None"),
+            Node::Some(ref value) => f.debug_tuple("Some").field(value).finish(),
+            Node::Seq(ref values) => f.debug_list().entries(values).finish(),
+            Node::Map(ref entries) => f
+                .debug_map()
+                .entries(entries.iter().map(|(k, v)| (k, v)))
+                .finish(),
+            Node::Struct(name, ref fields) => {
+                let mut s = f.debug_struct(name);
+                for &(field, ref value) in fields {
+                    s.field(field, value);
+                }
+                s.finish()
+            }
+            Node::Variant(name, ref content) => match **content {
+                Node::Unit => f.write_str(name),
+                Node::Seq(ref values) => {
+                    let mut t = f.debug_tuple(name);
+                    for value in values {
+                        t.field(value);
+                    }
+                    t.finish()
+                }
+                ref content @ Node::Struct(..) => content.fmt(f),
+                ref content => f.debug_tuple(name).field(content).finish(),
+            },
+        }
+    }
+}
+
+struct Diff<'a> {
+    tolerance: &'a Tolerance<f64>,
+    tolerance_f32: Tolerance<f32>,
+    path: String,
+    mismatches: Vec<FieldMismatch>,
+}
+
+impl<'a> Diff<'a> {
+    fn mismatch(&mut self, lhs: String, rhs: String) {
+        let path = if self.path.is_empty() {
+            String::from(".")
+        } else {
+            self.path.clone()
+        };
+        self.mismatches.push(FieldMismatch {
+            path,
+            left: lhs,
+            right: rhs,
+        });
+    }
+
+    fn missing(&mut self, lhs: Option<&Node>, rhs: Option<&Node>) {
+        let show = |node: Option<&Node>| match node {
+            Some(node) => std::format!("{:?}", node),
+            None => String::from("<missing>"),
+        };
+        self.mismatch(show(lhs), show(rhs));
+    }
+
+    fn compare_at(&mut self, segment: fmt::Arguments, lhs: Option<&Node>, rhs: Option<&Node>) {
+        let len = self.path.len();
+        fmt::Write::write_fmt(&mut self.path, segment).unwrap();
+        match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => self.compare(lhs, rhs),
+            (lhs, rhs) => self.missing(lhs, rhs),
+        }
+        self.path.truncate(len);
+    }
+
+    fn compare(&mut self, lhs: &Node, rhs: &Node) {
+        let equal = match (lhs, rhs) {
+            (Node::F32(a), Node::F32(b)) => self.tolerance_f32.eq(a, b),
+            (Node::F32(_), _) | (Node::F64(_), _) | (_, Node::F32(_)) | (_, Node::F64(_)) => {
+                match (lhs.as_f64(), rhs.as_f64()) {
+                    (Some(a), Some(b)) => self.tolerance.eq(&a, &b),
+                    _ => false,
+                }
+            }
+            (Node::Some(a), Node::Some(b)) => return self.compare(a, b),
+            (Node::Seq(a), Node::Seq(b)) => {
+                for i in 0..a.len().max(b.len()) {
+                    self.compare_at(format_args!("[{}]", i), a.get(i), b.get(i));
+                }
+                return;
+            }
+            (Node::Map(a), Node::Map(b)) => {
+                let a_keys: BTreeMap<_, _> = a.iter().map(|(key, value)| (key, value)).collect();
+                let b_keys: BTreeMap<_, _> = b.iter().map(|(key, value)| (key, value)).collect();
+                for (key, value) in a {
+                    let other = b_keys.get(key).cloned();
+                    self.compare_at(format_args!("[{:?}]", key), Some(value), other);
+                }
+                for (key, value) in b {
+                    if !a_keys.contains_key(key) {
+                        self.compare_at(format_args!("[{:?}]", key), None, Some(value));
+                    }
+                }
+                return;
+            }
+            (Node::Struct(_, a), Node::Struct(_, b)) => {
+                for &(name, ref value) in a {
+                    let other = b.iter().find(|entry| entry.0 == name).map(|entry| &entry.1);
+                    self.compare_at(format_args!(".{}", name), Some(value), other);
+                }
+                for &(name, ref value) in b {
+                    if !a.iter().any(|entry| entry.0 == name) {
+                        self.compare_at(format_args!(".{}", name), None, Some(value));
+                    }
+                }
+                return;
+            }
+            (Node::Variant(a, x), Node::Variant(b, y)) if a == b => return self.compare(x, y),
+            _ => lhs == rhs,
+        };
+        if !equal {
+            self.mismatch(std::format!("{:?}", lhs), std::format!("{:?}", rhs));
+        }
+    }
+}
+
+/// Serializes a value into a tree of nodes.
+struct TreeSerializer;
+
+struct SeqSerializer(Vec<Node>);
+
+struct VariantSerializer(&'static str, Vec<Node>);
+
+struct MapSerializer(Vec<(Node, Node)>, Option<Node>);
+
+struct StructSerializer(&'static str, Vec<(&'static str, Node)>);
+
+struct StructVariantSerializer(&'static str, Vec<(&'static str, Node)>);
+
+impl ser::Serializer for TreeSerializer {
+    type Ok = Node;
+    type Error = SerializeError;
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = VariantSerializer;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = StructSerializer;
+    type SerializeStructVariant = StructVariantSerializer;
+
+    fn serialize_bool(self, v: bool) -> Result<Node, SerializeError> {
+        Ok(Node::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Node, SerializeError> {
+        Ok(Node::I64(i64::from(v)))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Node, SerializeError> {
+        Ok(Node::I64(i64::from(v)))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Node, SerializeError> {
+        Ok(Node::I64(i64::from(v)))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Node, SerializeError> {
+        Ok(Node::I64(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Node, SerializeError> {
+        Ok(Node::U64(u64::from(v)))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Node, SerializeError> {
+        Ok(Node::U64(u64::from(v)))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Node, SerializeError> {
+        Ok(Node::U64(u64::from(v)))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Node, SerializeError> {
+        Ok(Node::U64(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Node, SerializeError> {
+        Ok(Node::F32(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Node, SerializeError> {
+        Ok(Node::F64(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Node, SerializeError> {
+        Ok(Node::Char(v))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Node, SerializeError> {
+        Ok(Node::Str(String::from(v)))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Node, SerializeError> {
+        Ok(Node::Bytes(v.to_vec()))
+    }
+
+    fn serialize_none(self) -> Result<Node, SerializeError> {
+        Ok(Node::None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Node, SerializeError> {
+        Ok(Node::Some(Box::new(value.serialize(TreeSerializer)?)))
+    }
+
+    fn serialize_unit(self) -> Result<Node, SerializeError> {
+        Ok(Node::Unit)
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Node, SerializeError> {
+        Ok(Node::Unit)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Node, SerializeError> {
+        Ok(Node::Variant(variant, Box::new(Node::Unit)))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Node, SerializeError> {
+        value.serialize(TreeSerializer)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Node, SerializeError> {
+        Ok(Node::Variant(
+            variant,
+            Box::new(value.serialize(TreeSerializer)?),
+        ))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqSerializer, SerializeError> {
+        Ok(SeqSerializer(Vec::with_capacity(len.unwrap_or(0))))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer, SerializeError> {
+        Ok(SeqSerializer(Vec::with_capacity(len)))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer, SerializeError> {
+        Ok(SeqSerializer(Vec::with_capacity(len)))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<VariantSerializer, SerializeError> {
+        Ok(VariantSerializer(variant, Vec::with_capacity(len)))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<MapSerializer, SerializeError> {
+        Ok(MapSerializer(Vec::with_capacity(len.unwrap_or(0)), None))
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<StructSerializer, SerializeError> {
+        Ok(StructSerializer(name, Vec::with_capacity(len)))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<StructVariantSerializer, SerializeError> {
+        Ok(StructVariantSerializer(variant, Vec::with_capacity(len)))
+    }
+}
+
+impl ser::SerializeSeq for SeqSerializer {
+    type Ok = Node;
+    type Error = SerializeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.0.push(value.serialize(TreeSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node, SerializeError> {
+        Ok(Node::Seq(self.0))
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer {
+    type Ok = Node;
+    type Error = SerializeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Node, SerializeError> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer {
+    type Ok = Node;
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerializeError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Node, SerializeError> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for VariantSerializer {
+    type Ok = Node;
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerializeError> {
+        self.1.push(value.serialize(TreeSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node, SerializeError> {
+        Ok(Node::Variant(self.0, Box::new(Node::Seq(self.1))))
+    }
+}
+
+impl ser::SerializeMap for MapSerializer {
+    type Ok = Node;
+    type Error = SerializeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), SerializeError> {
+        self.1 = Some(key.serialize(TreeSerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerializeError> {
+        let key = match self.1.take() {
+            Some(key) => key,
+            None => return Err(ser::Error::custom("map value serialized before its key")),
+        };
+        self.0.push((key, value.serialize(TreeSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node, SerializeError> {
+        Ok(Node::Map(self.0))
+    }
+}
+
+impl ser::SerializeStruct for StructSerializer {
+    type Ok = Node;
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.1.push((name, value.serialize(TreeSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node, SerializeError> {
+        Ok(Node::Struct(self.0, self.1))
+    }
+}
+
+impl ser::SerializeStructVariant for StructVariantSerializer {
+    type Ok = Node;
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.1.push((name, value.serialize(TreeSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node, SerializeError> {
+        Ok(Node::Variant(
+            self.0,
+            Box::new(Node::Struct(self.0, self.1)),
+        ))
+    }
+}
@@ -0,0 +1,200 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use std::vec::Vec;
+use {AbsDiff, AbsDiffEq, Comparator, Relative, RelativeEq, Ulps, UlpsEq};
+
+/// A comparison that is selected at runtime, for example from the data of a test case.
+///
+/// Each variant dispatches to the respective approximate equality trait, or to `PartialEq` for
+/// `Exact`, so the compared values must implement [`RelativeEq`], [`UlpsEq`] and `PartialEq` even
+/// if only the absolute or relative comparisons are used.
+///
+/// With the `serde` feature enabled this can be serialized and deserialized, with the variants
+/// and fields named in `snake_case`. For example, in JSON:
+///
+/// ```json
+/// { "any": [{ "abs": { "epsilon": 1e-12 } }, { "ulps": { "epsilon": 0.0, "max_ulps": 4 } }] }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use approx::Tolerance;
+///
+/// let tolerances = [
+///     Tolerance::Exact,
+///     Tolerance::Abs { epsilon: 0.5 },
+///     Tolerance::Relative { epsilon: 0.0, max_relative: 0.25 },
+///     Tolerance::Ulps { epsilon: 0.0, max_ulps: 4 },
+/// ];
+/// for tolerance in &tolerances {
+///     assert!(tolerance.eq(&1.0, &1.0));
+///     assert!(tolerance.ne(&1.0, &2.0));
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Tolerance<E> {
+    /// Compare the values using `==`.
+    Exact,
+    /// Compare the values using [`AbsDiffEq::abs_diff_eq`].
+    Abs {
+        /// The tolerance to use when testing values that are close together.
+        epsilon: E,
+    },
+    /// Compare the values using [`RelativeEq::relative_eq`].
+    Relative {
+        /// The tolerance to use when testing values that are close together.
+        epsilon: E,
+        /// The relative tolerance for testing values that are far-apart.
+        max_relative: E,
+    },
+    /// Compare the values using [`UlpsEq::ulps_eq`].
+    Ulps {
+        /// The tolerance to use when testing values that are close together.
+        epsilon: E,
+        /// The ULPs to tolerate when testing values that are far-apart.
+        max_ulps: u32,
+    },
+    /// Consider the values equal if any of the tolerances do. This is never equal if empty.
+    #[cfg(feature = "std")]
+    Any(Vec<Tolerance<E>>),
+    /// Consider the values equal if all of the tolerances do. This is always equal if empty.
+    #[cfg(feature = "std")]
+    All(Vec<Tolerance<E>>),
+}
+
+impl<E> Tolerance<E> {
+    /// Peform the equality comparison
+    #[must_use]
+    pub fn eq<A, B>(&self, lhs: &A, rhs: &B) -> bool
+    where
+        A: RelativeEq<B, Epsilon = E> + UlpsEq<B> + PartialEq<B> + ?Sized,
+        B: ?Sized,
+        E: Clone,
+    {
+        match *self {
+            Tolerance::Exact => lhs == rhs,
+            Tolerance::Abs { ref epsilon } => A::abs_diff_eq(lhs, rhs, epsilon.clone()),
+            Tolerance::Relative {
+                ref epsilon,
+                ref max_relative,
+            } => A::relative_eq(lhs, rhs, epsilon.clone(), max_relative.clone()),
+            Tolerance::Ulps {
+                ref epsilon,
+                max_ulps,
+            } => A::ulps_eq(lhs, rhs, epsilon.clone(), max_ulps),
+            #[cfg(feature = "std")]
+            Tolerance::Any(ref tolerances) => tolerances.iter().any(|t| t.eq(lhs, rhs)),
+            #[cfg(feature = "std")]
+            Tolerance::All(ref tolerances) => tolerances.iter().all(|t| t.eq(lhs, rhs)),
+        }
+    }
+
+    /// Peform the inequality comparison
+    #[must_use]
+    pub fn ne<A, B>(&self, lhs: &A, rhs: &B) -> bool
+    where
+        A: RelativeEq<B, Epsilon = E> + UlpsEq<B> + PartialEq<B> + ?Sized,
+        B: ?Sized,
+        E: Clone,
+    {
+        !self.eq(lhs, rhs)
+    }
+
+    /// Convert each of the tolerances, or return `None` if any cannot be converted.
+    pub(crate) fn try_map<U, F>(&self, f: &F) -> Option<Tolerance<U>>
+    where
+        F: Fn(&E) -> Option<U>,
+    {
+        Some(match *self {
+            Tolerance::Exact => Tolerance::Exact,
+            Tolerance::Abs { ref epsilon } => Tolerance::Abs {
+                epsilon: f(epsilon)?,
+            },
+            Tolerance::Relative {
+                ref epsilon,
+                ref max_relative,
+            } => Tolerance::Relative {
+                epsilon: f(epsilon)?,
+                max_relative: f(max_relative)?,
+            },
+            Tolerance::Ulps {
+                ref epsilon,
+                max_ulps,
+            } => Tolerance::Ulps {
+                epsilon: f(epsilon)?,
+                max_ulps,
+            },
+            #[cfg(feature = "std")]
+            Tolerance::Any(ref tolerances) => Tolerance::Any(
+                tolerances
+                    .iter()
+                    .map(|t| t.try_map(f))
+                    .collect::<Option<_>>()?,
+            ),
+            #[cfg(feature = "std")]
+            Tolerance::All(ref tolerances) => Tolerance::All(
+                tolerances
+                    .iter()
+                    .map(|t| t.try_map(f))
+                    .collect::<Option<_>>()?,
+            ),
+        })
+    }
+}
+
+impl<A, B> Comparator<A, B> for Tolerance<A::Epsilon>
+where
+    A: RelativeEq<B> + UlpsEq<B> + PartialEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: Clone,
+{
+    #[inline]
+    fn compare(&self, lhs: &A, rhs: &B) -> bool {
+        self.eq(lhs, rhs)
+    }
+}
+
+impl<A, B> From<AbsDiff<A, B>> for Tolerance<A::Epsilon>
+where
+    A: AbsDiffEq<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn from(comparison: AbsDiff<A, B>) -> Tolerance<A::Epsilon> {
+        Tolerance::Abs {
+            epsilon: comparison.epsilon,
+        }
+    }
+}
+
+impl<A, B> From<Relative<A, B>> for Tolerance<A::Epsilon>
+where
+    A: RelativeEq<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn from(comparison: Relative<A, B>) -> Tolerance<A::Epsilon> {
+        Tolerance::Relative {
+            epsilon: comparison.epsilon,
+            max_relative: comparison.max_relative,
+        }
+    }
+}
+
+impl<A, B> From<Ulps<A, B>> for Tolerance<A::Epsilon>
+where
+    A: UlpsEq<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn from(comparison: Ulps<A, B>) -> Tolerance<A::Epsilon> {
+        Tolerance::Ulps {
+            epsilon: comparison.epsilon,
+            max_ulps: comparison.max_ulps,
+        }
+    }
+}
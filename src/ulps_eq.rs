@@ -7,6 +7,8 @@ use num_traits::Signed;
 #[cfg(feature = "ordered-float")]
 use ordered_float::{NotNan, OrderedFloat};
 
+#[cfg(feature = "std")]
+use std::string::String;
 use AbsDiffEq;
 
 /// Equality comparisons between two numbers using both the absolute difference and ULPs
@@ -74,6 +76,28 @@ macro_rules! impl_ulps_eq {
 impl_ulps_eq!(f32, u32);
 impl_ulps_eq!(f64, u64);
 
+macro_rules! impl_exact_ulps_eq {
+    ($T:ty) => {
+        impl UlpsEq for $T {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                0
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$T, _: (), _: u32) -> bool {
+                self == other
+            }
+        }
+    };
+}
+
+impl_exact_ulps_eq!(bool);
+impl_exact_ulps_eq!(char);
+impl_exact_ulps_eq!(str);
+#[cfg(feature = "std")]
+impl_exact_ulps_eq!(String);
+
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // Derived implementations
 ///////////////////////////////////////////////////////////////////////////////////////////////////
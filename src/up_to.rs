@@ -0,0 +1,314 @@
+use core::ops::Neg;
+use num_traits::float::FloatCore;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+use {AbsDiff, AbsDiffEq, Relative, RelativeEq, Ulps, UlpsEq};
+
+/// Test that `lhs` and `rhs` are equal element-wise, or that `lhs` and `-rhs` are.
+fn eq_up_to_sign<A, B, F>(lhs: &[A], rhs: &[B], mut eq: F) -> bool
+where
+    B: Neg<Output = B> + Clone,
+    F: FnMut(&A, &B) -> bool,
+{
+    lhs.len() == rhs.len()
+        && (Iterator::zip(lhs.iter(), rhs).all(|(x, y)| eq(x, y))
+            || Iterator::zip(lhs.iter(), rhs).all(|(x, y)| eq(x, &-y.clone())))
+}
+
+/// Test that `lhs` is equal element-wise to `rhs` multiplied by some non-zero factor.
+///
+/// The factor is estimated from the largest element of `rhs`, in magnitude.
+fn eq_up_to_scale<T, F>(lhs: &[T], rhs: &[T], mut eq: F) -> bool
+where
+    T: FloatCore,
+    F: FnMut(&T, &T) -> bool,
+{
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+
+    let mut pivot = None;
+    let mut largest = T::zero();
+    for (i, y) in rhs.iter().enumerate() {
+        if y.abs() > largest {
+            pivot = Some(i);
+            largest = y.abs();
+        }
+    }
+    let scale = match pivot {
+        Some(i) => lhs[i] / rhs[i],
+        // A zero vector is only equivalent to another zero vector
+        None => return Iterator::zip(lhs.iter(), rhs).all(|(x, y)| eq(x, y)),
+    };
+    if scale == T::zero() || !scale.is_finite() {
+        return false;
+    }
+
+    Iterator::zip(lhs.iter(), rhs).all(|(&x, &y)| eq(&x, &(y * scale)))
+}
+
+/// Test that there is a one-to-one matching between the elements of `lhs` and `rhs` such that
+/// each pair of matched elements are equal.
+///
+/// This uses augmenting paths to find a maximum matching in the bipartite graph of equal
+/// elements, so unlike a greedy matching it does not fail when an element of `lhs` is close to
+/// several elements of `rhs`.
+#[cfg(feature = "std")]
+fn unordered_eq<A, B, F>(lhs: &[A], rhs: &[B], mut eq: F) -> bool
+where
+    F: FnMut(&A, &B) -> bool,
+{
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+
+    let n = lhs.len();
+    let mut adjacent = Vec::with_capacity(n * n);
+    for x in lhs {
+        adjacent.extend(rhs.iter().map(|y| eq(x, y)));
+    }
+
+    // The element of `lhs` currently matched with each element of `rhs`
+    let mut matches: Vec<Option<usize>> = (0..n).map(|_| None).collect();
+    let mut visited: Vec<bool> = (0..n).map(|_| false).collect();
+    for i in 0..n {
+        for v in visited.iter_mut() {
+            *v = false;
+        }
+        if !augment(i, n, &adjacent, &mut matches, &mut visited) {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(feature = "std")]
+fn augment(
+    i: usize,
+    n: usize,
+    adjacent: &[bool],
+    matches: &mut [Option<usize>],
+    visited: &mut [bool],
+) -> bool {
+    for j in 0..n {
+        if adjacent[i * n + j] && !visited[j] {
+            visited[j] = true;
+            let free = match matches[j] {
+                None => true,
+                Some(k) => augment(k, n, adjacent, matches, visited),
+            };
+            if free {
+                matches[j] = Some(i);
+                return true;
+            }
+        }
+    }
+    false
+}
+
+impl<A, B> AbsDiff<A, B>
+where
+    A: AbsDiffEq<B>,
+    A::Epsilon: Clone,
+{
+    /// Peform the equality comparison of two slices, also accepting `rhs` with all of its
+    /// elements negated.
+    ///
+    /// This is useful for values that are only defined up to their sign, such as eigenvectors.
+    ///
+    /// ```rust
+    /// use approx::AbsDiff;
+    ///
+    /// assert!(AbsDiff::default().eq_up_to_sign(&[1.0, -2.0], &[-1.0, 2.0]));
+    /// assert!(!AbsDiff::default().eq_up_to_sign(&[1.0, -2.0], &[-1.0, -2.0]));
+    /// ```
+    #[must_use]
+    pub fn eq_up_to_sign(&self, lhs: &[A], rhs: &[B]) -> bool
+    where
+        B: Neg<Output = B> + Clone,
+    {
+        eq_up_to_sign(lhs, rhs, |x, y| A::abs_diff_eq(x, y, self.epsilon.clone()))
+    }
+
+    /// Peform the equality comparison of two slices, ignoring the order of their elements.
+    ///
+    /// The slices are considered equal if each element of `lhs` can be paired with a distinct
+    /// equal element of `rhs`. This is useful for unordered collections, such as eigenvalues.
+    ///
+    /// ```rust
+    /// use approx::AbsDiff;
+    ///
+    /// assert!(AbsDiff::default().unordered_eq(&[1.0, 2.0, 2.0], &[2.0, 1.0, 2.0]));
+    /// assert!(!AbsDiff::default().unordered_eq(&[1.0, 1.0, 2.0], &[2.0, 1.0, 2.0]));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn unordered_eq(&self, lhs: &[A], rhs: &[B]) -> bool {
+        unordered_eq(lhs, rhs, |x, y| A::abs_diff_eq(x, y, self.epsilon.clone()))
+    }
+}
+
+impl<T> AbsDiff<T>
+where
+    T: AbsDiffEq + FloatCore,
+    T::Epsilon: Clone,
+{
+    /// Peform the equality comparison of two slices, also accepting `rhs` multiplied by any
+    /// non-zero factor.
+    ///
+    /// This is useful for values that are only defined up to scale, such as homogeneous
+    /// coordinates.
+    ///
+    /// ```rust
+    /// use approx::AbsDiff;
+    ///
+    /// assert!(AbsDiff::default().eq_up_to_scale(&[1.0, 2.0, 4.0], &[-0.5, -1.0, -2.0]));
+    /// assert!(!AbsDiff::default().eq_up_to_scale(&[1.0, 2.0, 4.0], &[1.0, 2.0, 2.0]));
+    /// ```
+    #[must_use]
+    pub fn eq_up_to_scale(&self, lhs: &[T], rhs: &[T]) -> bool {
+        eq_up_to_scale(lhs, rhs, |x, y| T::abs_diff_eq(x, y, self.epsilon.clone()))
+    }
+}
+
+impl<A, B> Relative<A, B>
+where
+    A: RelativeEq<B>,
+    A::Epsilon: Clone,
+{
+    /// Peform the equality comparison of two slices, also accepting `rhs` with all of its
+    /// elements negated.
+    ///
+    /// This is useful for values that are only defined up to their sign, such as eigenvectors.
+    ///
+    /// ```rust
+    /// use approx::Relative;
+    ///
+    /// assert!(Relative::default().eq_up_to_sign(&[1.0, -2.0], &[-1.0, 2.0]));
+    /// assert!(!Relative::default().eq_up_to_sign(&[1.0, -2.0], &[-1.0, -2.0]));
+    /// ```
+    #[must_use]
+    pub fn eq_up_to_sign(&self, lhs: &[A], rhs: &[B]) -> bool
+    where
+        B: Neg<Output = B> + Clone,
+    {
+        eq_up_to_sign(lhs, rhs, |x, y| {
+            A::relative_eq(x, y, self.epsilon.clone(), self.max_relative.clone())
+        })
+    }
+
+    /// Peform the equality comparison of two slices, ignoring the order of their elements.
+    ///
+    /// The slices are considered equal if each element of `lhs` can be paired with a distinct
+    /// equal element of `rhs`. This is useful for unordered collections, such as eigenvalues.
+    ///
+    /// ```rust
+    /// use approx::Relative;
+    ///
+    /// assert!(Relative::default().unordered_eq(&[1.0, 2.0, 2.0], &[2.0, 1.0, 2.0]));
+    /// assert!(!Relative::default().unordered_eq(&[1.0, 1.0, 2.0], &[2.0, 1.0, 2.0]));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn unordered_eq(&self, lhs: &[A], rhs: &[B]) -> bool {
+        unordered_eq(lhs, rhs, |x, y| {
+            A::relative_eq(x, y, self.epsilon.clone(), self.max_relative.clone())
+        })
+    }
+}
+
+impl<T> Relative<T>
+where
+    T: RelativeEq + FloatCore,
+    T::Epsilon: Clone,
+{
+    /// Peform the equality comparison of two slices, also accepting `rhs` multiplied by any
+    /// non-zero factor.
+    ///
+    /// This is useful for values that are only defined up to scale, such as homogeneous
+    /// coordinates.
+    ///
+    /// ```rust
+    /// use approx::Relative;
+    ///
+    /// assert!(Relative::default().eq_up_to_scale(&[1.0, 2.0, 4.0], &[-0.5, -1.0, -2.0]));
+    /// assert!(!Relative::default().eq_up_to_scale(&[1.0, 2.0, 4.0], &[1.0, 2.0, 2.0]));
+    /// ```
+    #[must_use]
+    pub fn eq_up_to_scale(&self, lhs: &[T], rhs: &[T]) -> bool {
+        eq_up_to_scale(lhs, rhs, |x, y| {
+            T::relative_eq(x, y, self.epsilon.clone(), self.max_relative.clone())
+        })
+    }
+}
+
+impl<A, B> Ulps<A, B>
+where
+    A: UlpsEq<B>,
+    A::Epsilon: Clone,
+{
+    /// Peform the equality comparison of two slices, also accepting `rhs` with all of its
+    /// elements negated.
+    ///
+    /// This is useful for values that are only defined up to their sign, such as eigenvectors.
+    ///
+    /// ```rust
+    /// use approx::Ulps;
+    ///
+    /// assert!(Ulps::default().eq_up_to_sign(&[1.0, -2.0], &[-1.0, 2.0]));
+    /// assert!(!Ulps::default().eq_up_to_sign(&[1.0, -2.0], &[-1.0, -2.0]));
+    /// ```
+    #[must_use]
+    pub fn eq_up_to_sign(&self, lhs: &[A], rhs: &[B]) -> bool
+    where
+        B: Neg<Output = B> + Clone,
+    {
+        eq_up_to_sign(lhs, rhs, |x, y| {
+            A::ulps_eq(x, y, self.epsilon.clone(), self.max_ulps)
+        })
+    }
+
+    /// Peform the equality comparison of two slices, ignoring the order of their elements.
+    ///
+    /// The slices are considered equal if each element of `lhs` can be paired with a distinct
+    /// equal element of `rhs`. This is useful for unordered collections, such as eigenvalues.
+    ///
+    /// ```rust
+    /// use approx::Ulps;
+    ///
+    /// assert!(Ulps::default().unordered_eq(&[1.0, 2.0, 2.0], &[2.0, 1.0, 2.0]));
+    /// assert!(!Ulps::default().unordered_eq(&[1.0, 1.0, 2.0], &[2.0, 1.0, 2.0]));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn unordered_eq(&self, lhs: &[A], rhs: &[B]) -> bool {
+        unordered_eq(lhs, rhs, |x, y| {
+            A::ulps_eq(x, y, self.epsilon.clone(), self.max_ulps)
+        })
+    }
+}
+
+impl<T> Ulps<T>
+where
+    T: UlpsEq + FloatCore,
+    T::Epsilon: Clone,
+{
+    /// Peform the equality comparison of two slices, also accepting `rhs` multiplied by any
+    /// non-zero factor.
+    ///
+    /// This is useful for values that are only defined up to scale, such as homogeneous
+    /// coordinates.
+    ///
+    /// ```rust
+    /// use approx::Ulps;
+    ///
+    /// assert!(Ulps::default().eq_up_to_scale(&[1.0, 2.0, 4.0], &[-0.5, -1.0, -2.0]));
+    /// assert!(!Ulps::default().eq_up_to_scale(&[1.0, 2.0, 4.0], &[1.0, 2.0, 2.0]));
+    /// ```
+    #[must_use]
+    pub fn eq_up_to_scale(&self, lhs: &[T], rhs: &[T]) -> bool {
+        eq_up_to_scale(lhs, rhs, |x, y| {
+            T::ulps_eq(x, y, self.epsilon.clone(), self.max_ulps)
+        })
+    }
+}
@@ -0,0 +1,234 @@
+use core::fmt;
+use num_traits::float::FloatCore;
+#[cfg(feature = "std")]
+use std::error::Error;
+use {AbsDiff, AbsDiffEq, Relative, RelativeEq, Ulps, UlpsEq};
+
+/// The reason that a tolerance was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidToleranceErrorKind {
+    /// The tolerance was less than zero.
+    Negative,
+    /// The tolerance was NaN.
+    NaN,
+    /// The tolerance was infinite.
+    Infinite,
+}
+
+/// An error which is returned when a tolerance is negative, NaN or infinite.
+///
+/// # Example
+///
+/// ```rust
+/// use approx::{Epsilon, InvalidToleranceErrorKind};
+///
+/// let error = Epsilon::new(-1e-9).unwrap_err();
+/// assert_eq!(error.kind(), InvalidToleranceErrorKind::Negative);
+/// assert_eq!(error.to_string(), "`epsilon` must not be negative");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidToleranceError {
+    kind: InvalidToleranceErrorKind,
+    name: &'static str,
+}
+
+impl InvalidToleranceError {
+    /// The reason that the tolerance was rejected.
+    #[inline]
+    pub fn kind(&self) -> InvalidToleranceErrorKind {
+        self.kind
+    }
+
+    /// The name of the tolerance that was rejected, such as `epsilon` or `max_relative`.
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl fmt::Display for InvalidToleranceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reason = match self.kind {
+            InvalidToleranceErrorKind::Negative => "must not be negative",
+            InvalidToleranceErrorKind::NaN => "must not be NaN",
+            InvalidToleranceErrorKind::Infinite => "must be finite",
+        };
+        write!(f, "`{}` {}", self.name, reason)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for InvalidToleranceError {}
+
+/// Check that a tolerance is zero or positive, and finite.
+fn validate<T: FloatCore>(name: &'static str, value: T) -> Result<T, InvalidToleranceError> {
+    let kind = if value.is_nan() {
+        InvalidToleranceErrorKind::NaN
+    } else if value < T::zero() {
+        InvalidToleranceErrorKind::Negative
+    } else if value.is_infinite() {
+        InvalidToleranceErrorKind::Infinite
+    } else {
+        return Ok(value);
+    };
+    Err(InvalidToleranceError { kind, name })
+}
+
+/// An absolute tolerance that is known to be zero or positive, and finite.
+///
+/// # Example
+///
+/// ```rust
+/// use approx::{AbsDiff, Epsilon};
+///
+/// let epsilon = Epsilon::new(1e-9).unwrap();
+/// assert!(AbsDiff::default().epsilon(epsilon.get()).eq(&1.0, &1.0000000001));
+/// assert!(Epsilon::new(-1e-9).is_err());
+/// assert!(Epsilon::new(f64::NAN).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Epsilon<T>(T);
+
+impl<T: FloatCore> Epsilon<T> {
+    /// Validate an absolute tolerance, rejecting negative, NaN and infinite values.
+    #[inline]
+    pub fn new(epsilon: T) -> Result<Epsilon<T>, InvalidToleranceError> {
+        validate("epsilon", epsilon).map(Epsilon)
+    }
+
+    /// The validated tolerance.
+    #[inline]
+    pub fn get(self) -> T {
+        self.0
+    }
+}
+
+/// A relative tolerance that is known to be zero or positive, and finite.
+///
+/// # Example
+///
+/// ```rust
+/// use approx::{MaxRelative, Relative};
+///
+/// let max_relative = MaxRelative::new(1e-6).unwrap();
+/// assert!(Relative::default().max_relative(max_relative.get()).eq(&1e10, &1.0000001e10));
+/// assert!(MaxRelative::new(-1e-6).is_err());
+/// assert!(MaxRelative::new(f64::INFINITY).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct MaxRelative<T>(T);
+
+impl<T: FloatCore> MaxRelative<T> {
+    /// Validate a relative tolerance, rejecting negative, NaN and infinite values.
+    #[inline]
+    pub fn new(max_relative: T) -> Result<MaxRelative<T>, InvalidToleranceError> {
+        validate("max_relative", max_relative).map(MaxRelative)
+    }
+
+    /// The validated tolerance.
+    #[inline]
+    pub fn get(self) -> T {
+        self.0
+    }
+}
+
+impl<A, B> AbsDiff<A, B>
+where
+    A: AbsDiffEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: FloatCore,
+{
+    /// Replace the epsilon value with the one specified, or return an error if it is negative,
+    /// NaN or infinite.
+    #[inline]
+    pub fn try_epsilon(self, epsilon: A::Epsilon) -> Result<AbsDiff<A, B>, InvalidToleranceError> {
+        Ok(self.epsilon(Epsilon::new(epsilon)?.get()))
+    }
+}
+
+impl<A, B> Relative<A, B>
+where
+    A: RelativeEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: FloatCore,
+{
+    /// Replace the epsilon value with the one specified, or return an error if it is negative,
+    /// NaN or infinite.
+    #[inline]
+    pub fn try_epsilon(self, epsilon: A::Epsilon) -> Result<Relative<A, B>, InvalidToleranceError> {
+        Ok(self.epsilon(Epsilon::new(epsilon)?.get()))
+    }
+
+    /// Replace the maximum relative value with the one specified, or return an error if it is
+    /// negative, NaN or infinite.
+    #[inline]
+    pub fn try_max_relative(
+        self,
+        max_relative: A::Epsilon,
+    ) -> Result<Relative<A, B>, InvalidToleranceError> {
+        Ok(self.max_relative(MaxRelative::new(max_relative)?.get()))
+    }
+}
+
+impl<A, B> Ulps<A, B>
+where
+    A: UlpsEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: FloatCore,
+{
+    /// Replace the epsilon value with the one specified, or return an error if it is negative,
+    /// NaN or infinite.
+    #[inline]
+    pub fn try_epsilon(self, epsilon: A::Epsilon) -> Result<Ulps<A, B>, InvalidToleranceError> {
+        Ok(self.epsilon(Epsilon::new(epsilon)?.get()))
+    }
+}
+
+/// The macro options that are checked by [`__CheckFloat`].
+const CHECKED_OPTIONS: &[&str] = &[
+    "epsilon",
+    "max_relative",
+    "max_log_diff",
+    "max_decibels",
+    "scale",
+    "period",
+    "abs",
+    "rel",
+];
+
+/// Used by the macros to check the tolerances that they are passed in debug builds.
+///
+/// The macros only call `check` when `debug_assertions` are enabled in the crate that uses them,
+/// rather than in this crate.
+///
+/// This relies on method resolution preferring [`__CheckFloat`], which takes `&__Check`, over
+/// [`__CheckOther`], which takes `&&__Check`, so that only floating point tolerances are
+/// checked and other tolerance types are passed through unchanged.
+#[doc(hidden)]
+pub struct __Check<'a, T: 'a>(pub &'a T);
+
+#[doc(hidden)]
+pub trait __CheckFloat {
+    fn check(&self, option: &str, value: &str);
+}
+
+impl<'a, T: FloatCore> __CheckFloat for __Check<'a, T> {
+    #[inline]
+    fn check(&self, option: &str, value: &str) {
+        if let Some(name) = CHECKED_OPTIONS.iter().find(|name| **name == option) {
+            if let Err(error) = validate(name, *self.0) {
+                panic!("invalid tolerance `{} = {}`: {}", option, value, error);
+            }
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait __CheckOther {
+    fn check(&self, option: &str, value: &str);
+}
+
+impl<'a, 'b, T> __CheckOther for &'b __Check<'a, T> {
+    #[inline]
+    fn check(&self, _: &str, _: &str) {}
+}
@@ -499,3 +499,42 @@ mod test_ordered_float {
         }
     }
 }
+
+mod test_without_partial_eq {
+    use approx::{AbsDiff, AbsDiffEq, RelativeEq};
+
+    /// A type that deliberately does not implement `PartialEq`.
+    #[derive(Debug)]
+    struct Samples(&'static [f64]);
+
+    impl AbsDiffEq for Samples {
+        type Epsilon = f64;
+
+        fn default_epsilon() -> f64 {
+            f64::default_epsilon()
+        }
+
+        fn abs_diff_eq(&self, other: &Samples, epsilon: f64) -> bool {
+            self.0.abs_diff_eq(other.0, epsilon)
+        }
+    }
+
+    impl RelativeEq for Samples {
+        fn default_max_relative() -> f64 {
+            f64::default_max_relative()
+        }
+
+        fn relative_eq(&self, other: &Samples, epsilon: f64, max_relative: f64) -> bool {
+            self.0.relative_eq(other.0, epsilon, max_relative)
+        }
+    }
+
+    #[test]
+    fn test_basic() {
+        assert_abs_diff_eq!(Samples(&[1.0, 2.0]), Samples(&[1.0, 2.05]), epsilon = 0.1);
+        assert_abs_diff_ne!(Samples(&[1.0, 2.0]), Samples(&[1.0, 2.5]), epsilon = 0.1);
+        assert_relative_eq!(Samples(&[1.0]), Samples(&[1.0]));
+        assert_relative_ne!(Samples(&[1.0]), Samples(&[1.1]));
+        assert!(AbsDiff::default().eq(&Samples(&[0.0]), &Samples(&[0.0])));
+    }
+}
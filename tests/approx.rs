@@ -0,0 +1,121 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+
+use approx::{AbsDiff, Approx, Comparator, Ulps};
+use std::{f32, f64};
+
+mod test_approx {
+    use super::*;
+
+    #[test]
+    fn test_abs_diff() {
+        assert_eq!(Approx::abs_diff(1.0), 1.0);
+        assert_eq!(Approx::abs_diff(1.0).epsilon(0.1), 1.05);
+        assert_ne!(Approx::abs_diff(1.0).epsilon(0.1), 1.2);
+        assert_eq!(1.05, Approx::abs_diff(1.0).epsilon(0.1));
+        assert_ne!(1.2f32, Approx::abs_diff(1.0f32).epsilon(0.1));
+    }
+
+    #[test]
+    fn test_relative() {
+        assert_eq!(0.1 + 0.2, Approx::relative(0.3));
+        assert_ne!(0.3, Approx::relative(0.31));
+        assert_eq!(0.3, Approx::relative(0.31).max_relative(0.1));
+        assert_eq!(1e-10, Approx::relative(0.0).epsilon(1e-9));
+    }
+
+    #[test]
+    fn test_ulps() {
+        assert_eq!(1.0 + f64::EPSILON, Approx::ulps(1.0));
+        assert_ne!(1.0 + 8.0 * f64::EPSILON, Approx::ulps(1.0));
+        assert_eq!(1.0 + 8.0 * f32::EPSILON, Approx::ulps(1.0f32).max_ulps(8));
+        assert_ne!(1e-10, Approx::ulps(0.0).epsilon(1e-12));
+    }
+
+    #[test]
+    fn test_comparators() {
+        let within = AbsDiff::default()
+            .epsilon(1e-12)
+            .or(Ulps::default().max_ulps(4));
+        let expected = Approx::new(0.0, within);
+        assert!(expected == 1e-13);
+        assert!(expected != 1e-11);
+    }
+
+    #[test]
+    fn test_other_types() {
+        assert!(Approx::relative(true) == true);
+        assert!(Approx::abs_diff(3i32).epsilon(1) == 4);
+        assert!(true == Approx::relative(true));
+        assert!(4 == Approx::abs_diff(3i32).epsilon(1));
+        assert!(5u8 != Approx::abs_diff(3u8).epsilon(1));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_string() {
+        assert!(Approx::ulps("a".to_string()) != "b".to_string());
+        assert!("a".to_string() == Approx::ulps("a".to_string()));
+    }
+
+    #[test]
+    fn test_collections() {
+        let results = vec![0.1 + 0.2, 0.2 + 0.5];
+        let expected: Vec<_> = [0.3, 0.7].iter().map(|&x| Approx::relative(x)).collect();
+        assert_eq!(results, expected);
+        assert!(results.iter().any(|&x| x == Approx::relative(0.7)));
+        assert!(!results.iter().any(|&x| x == Approx::relative(0.5)));
+    }
+
+    #[test]
+    fn test_debug() {
+        assert_eq!(
+            format!("{:?}", Approx::abs_diff(1.0).epsilon(0.5)),
+            "1.0 ± AbsDiff { epsilon: 0.5 }"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                Approx::relative(1.0).epsilon(0.0).max_relative(1e-6)
+            ),
+            "1.0 ± Relative { epsilon: 0.0, max_relative: 1e-6 }"
+        );
+        assert_eq!(
+            format!("{:?}", Approx::ulps(1.0f32).epsilon(0.0).max_ulps(8)),
+            "1.0 ± Ulps { epsilon: 0.0, max_ulps: 8 }"
+        );
+    }
+
+    #[test]
+    fn test_debug_comparators() {
+        let within = AbsDiff::default()
+            .epsilon(1e-12)
+            .or(Ulps::default().epsilon(0.0).max_ulps(4));
+        assert_eq!(
+            format!("{:?}", Approx::new(0.0, within)),
+            "0.0 ± Or(AbsDiff { epsilon: 1e-12 }, Ulps { epsilon: 0.0, max_ulps: 4 })"
+        );
+        assert_eq!(1e-13, Approx::new(0.0, within));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "right: 0.3 ± Relative { epsilon: 2.220446049250313e-16, max_relative: 2.220446049250313e-16 }"
+    )]
+    fn test_assert_eq_message() {
+        assert_eq!(0.31, Approx::relative(0.3));
+    }
+}
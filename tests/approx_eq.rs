@@ -0,0 +1,161 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+
+mod test_bridges {
+    use approx::{AbsDiff, ApproxEq, Periodic, Relative, Tolerance, Ulps};
+    use std::f64;
+
+    #[test]
+    fn test_abs_diff() {
+        assert!(1.0f64.approx_eq(&1.05, &AbsDiff::default().epsilon(0.1)));
+        assert!(1.0f64.approx_ne(&1.2, &AbsDiff::default().epsilon(0.1)));
+    }
+
+    #[test]
+    fn test_relative() {
+        assert!(1.0f64.approx_eq(&(1.0 + f64::EPSILON), &Relative::default()));
+        assert!(1.0f64.approx_ne(&1.1, &Relative::default()));
+        assert!(1.0f64.approx_eq(&1.1, &Relative::default().max_relative(0.2)));
+    }
+
+    #[test]
+    fn test_ulps() {
+        assert!(1.0f32.approx_eq(&1.0000001, &Ulps::default()));
+        assert!(1.0f32.approx_ne(&1.0001, &Ulps::default().max_ulps(4)));
+    }
+
+    #[test]
+    fn test_scaled() {
+        let (a, b) = (1e10f64, 1e10 + 1e-3);
+        let tolerance = Relative::default().max_relative(1e-12).scale(a);
+        assert!((a - b).approx_eq(&0.0, &tolerance));
+        assert!((a - b).approx_ne(&0.0, &Relative::default().max_relative(1e-12)));
+    }
+
+    #[test]
+    fn test_periodic() {
+        assert!(359.0f64.approx_eq(&-1.0, &Periodic::degrees()));
+        assert!(1.0f32.approx_ne(&-1.0, &Periodic::degrees()));
+    }
+
+    #[test]
+    fn test_tolerance() {
+        assert!(1.0f64.approx_eq(&1.05, &Tolerance::Abs { epsilon: 0.1 }));
+        assert!(1.0f64.approx_ne(&1.05, &Tolerance::Exact));
+    }
+
+    #[test]
+    fn test_slice() {
+        let tolerance = AbsDiff::default().epsilon(0.1);
+        assert!([1.0f64, 2.0][..].approx_eq(&[1.05, 2.05][..], &tolerance));
+        assert!([1.0f64, 2.0][..].approx_ne(&[1.05, 2.2][..], &tolerance));
+    }
+}
+
+mod test_combinators {
+    use approx::{AbsDiff, And, ApproxEq, Not, Or, Relative, Ulps};
+
+    #[test]
+    fn test_or() {
+        let within = Or(
+            AbsDiff::default().epsilon(1e-12),
+            Ulps::default().max_ulps(4),
+        );
+        assert!(1e-13f64.approx_eq(&0.0, &within));
+        assert!(1e10f64.approx_eq(&(1e10 + 2e-6), &within));
+        assert!(1e-11f64.approx_ne(&0.0, &within));
+    }
+
+    #[test]
+    fn test_and() {
+        let within = And(
+            Relative::default().epsilon(0.0).max_relative(1e-6),
+            AbsDiff::default().epsilon(1e-3),
+        );
+        assert!(1.0f64.approx_eq(&1.0000001, &within));
+        assert!(1e4f64.approx_ne(&(1e4 + 5e-3), &within));
+    }
+
+    #[test]
+    fn test_not() {
+        assert!(1.0f64.approx_eq(&2.0, &Not(AbsDiff::default())));
+        assert!(1.0f64.approx_ne(&1.0, &Not(AbsDiff::default())));
+    }
+}
+
+mod test_user_defined {
+    use approx::{AbsDiff, AbsDiffEq, And, ApproxEq, Or};
+
+    /// Integers within a number of steps of each other.
+    struct Steps(u32);
+
+    impl ApproxEq<i32, Steps> for i32 {
+        fn approx_eq(&self, other: &i32, tolerance: &Steps) -> bool {
+            (i64::from(*self) - i64::from(*other)).abs() <= i64::from(tolerance.0)
+        }
+    }
+
+    /// Values within a percentage of the larger magnitude.
+    struct Percent(f64);
+
+    impl ApproxEq<f64, Percent> for f64 {
+        fn approx_eq(&self, other: &f64, tolerance: &Percent) -> bool {
+            (self - other).abs() <= self.abs().max(other.abs()) * tolerance.0 / 100.0
+        }
+    }
+
+    #[test]
+    fn test_user_defined() {
+        assert!(3.approx_eq(&5, &Steps(2)));
+        assert!(3.approx_ne(&6, &Steps(2)));
+        assert!(100.0f64.approx_eq(&101.0, &Percent(1.0)));
+        assert!(100.0f64.approx_ne(&102.0, &Percent(1.0)));
+    }
+
+    #[test]
+    fn test_combined() {
+        let within = Or(AbsDiff::default().epsilon(1e-6), Percent(1.0));
+        assert!(0.0f64.approx_eq(&1e-7, &within));
+        assert!(100.0f64.approx_eq(&101.0, &within));
+        assert!(1.0f64.approx_ne(&1.1, &within));
+
+        let within = And(AbsDiff::default().epsilon(1.0), Percent(1.0));
+        assert!(100.0f64.approx_eq(&100.5, &within));
+        assert!(1000.0f64.approx_ne(&1005.0, &within));
+    }
+
+    /// A type without `PartialEq`, compared using `AbsDiffEq` alone.
+    struct Position(f64);
+
+    impl AbsDiffEq for Position {
+        type Epsilon = f64;
+
+        fn default_epsilon() -> f64 {
+            f64::default_epsilon()
+        }
+
+        fn abs_diff_eq(&self, other: &Position, epsilon: f64) -> bool {
+            self.0.abs_diff_eq(&other.0, epsilon)
+        }
+    }
+
+    #[test]
+    fn test_without_partial_eq() {
+        let tolerance = AbsDiff::default().epsilon(0.1);
+        assert!(Position(1.0).approx_eq(&Position(1.05), &tolerance));
+        assert!(Position(1.0).approx_ne(&Position(1.2), &tolerance));
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+
+use approx::RelativeEq;
+use std::fmt::Debug;
+use std::{f32, f64};
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Number(i32),
+    Word(&'static str),
+}
+
+fn check_relative<T: RelativeEq + Debug>(given: T, expected: T) {
+    assert_approx_or_eq!(given, expected);
+}
+
+fn check_exact<T: PartialEq + Debug>(given: T, expected: T) {
+    assert_approx_or_eq!(given, expected);
+}
+
+mod test_approx_or_eq {
+    use super::*;
+
+    #[test]
+    fn test_floats() {
+        assert!(approx_or_eq!(0.1 + 0.2, 0.3));
+        assert!(approx_or_eq!(1.0f32, 1.0 + f32::EPSILON));
+        assert!(approx_or_ne!(1.0, 1.1));
+        assert!(approx_or_eq!([1.0, 2.0][..], [1.0, 2.0 + f64::EPSILON][..]));
+    }
+
+    #[test]
+    fn test_other_types() {
+        assert!(approx_or_eq!(Token::Number(1), Token::Number(1)));
+        assert!(approx_or_ne!(Token::Number(1), Token::Word("one")));
+        assert!(approx_or_eq!(Some(1.0), Some(1.0)));
+        assert!(approx_or_ne!(Some(0.1 + 0.2), Some(0.3)));
+    }
+
+    #[test]
+    fn test_trailing_comma() {
+        assert!(approx_or_eq!(1.0, 1.0,));
+        assert!(approx_or_ne!(1.0, 2.0,));
+    }
+
+    #[test]
+    fn test_generic() {
+        check_relative(0.1 + 0.2, 0.3);
+        check_exact(Token::Word("a"), Token::Word("a"));
+        check_exact(0.5, 0.5);
+    }
+
+    // Without a `RelativeEq` bound the comparison falls back to `==`, as documented.
+    #[test]
+    #[should_panic]
+    fn test_generic_without_bound() {
+        check_exact(0.1 + 0.2, 0.3);
+    }
+}
+
+mod test_assert_approx_or_eq {
+    use super::*;
+
+    #[test]
+    fn test_macros() {
+        assert_approx_or_eq!(0.1 + 0.2, 0.3);
+        assert_approx_or_eq!("a", "a",);
+        assert_approx_or_ne!(1.0, 1.1);
+        assert_approx_or_ne!(Token::Number(1), Token::Number(2),);
+    }
+
+    #[test]
+    #[should_panic(expected = "assert_approx_or_eq!(1.0, 1.1)
+
+    left  = 1.0
+    right = 1.1
+")]
+    fn test_message() {
+        assert_approx_or_eq!(1.0, 1.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "assert_approx_or_ne!(Token::Number(1), Token::Number(1))")]
+    fn test_message_exact() {
+        assert_approx_or_ne!(Token::Number(1), Token::Number(1));
+    }
+}
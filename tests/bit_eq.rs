@@ -0,0 +1,193 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+
+mod test_f32 {
+    use std::f32;
+
+    #[test]
+    fn test_basic() {
+        assert_bit_eq!(1.0f32, 1.0f32);
+        assert_bit_ne!(1.0f32, 2.0f32);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_eq() {
+        assert_bit_eq!(1.0f32, 2.0f32);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_ne() {
+        assert_bit_ne!(1.0f32, 1.0f32);
+    }
+
+    #[test]
+    fn test_zero() {
+        assert_bit_eq!(0.0f32, 0.0f32);
+        assert_bit_eq!(-0.0f32, -0.0f32);
+        assert_bit_ne!(0.0f32, -0.0f32);
+        assert_bit_ne!(-0.0f32, 0.0f32);
+    }
+
+    #[test]
+    fn test_nan() {
+        assert_bit_eq!(f32::NAN, f32::NAN);
+        assert_bit_ne!(f32::NAN, -f32::NAN);
+        assert_bit_ne!(f32::NAN, f32::from_bits(f32::NAN.to_bits() | 1));
+    }
+
+    #[test]
+    fn test_adjacent() {
+        assert_bit_ne!(1.0f32, f32::from_bits(1.0f32.to_bits() + 1));
+    }
+}
+
+mod test_f64 {
+    use std::f64;
+
+    #[test]
+    fn test_basic() {
+        assert_bit_eq!(1.0f64, 1.0f64);
+        assert_bit_ne!(1.0f64, 2.0f64);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_eq() {
+        assert_bit_eq!(1.0f64, 2.0f64);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_ne() {
+        assert_bit_ne!(1.0f64, 1.0f64);
+    }
+
+    #[test]
+    fn test_zero() {
+        assert_bit_eq!(0.0f64, 0.0f64);
+        assert_bit_eq!(-0.0f64, -0.0f64);
+        assert_bit_ne!(0.0f64, -0.0f64);
+        assert_bit_ne!(-0.0f64, 0.0f64);
+    }
+
+    #[test]
+    fn test_nan() {
+        assert_bit_eq!(f64::NAN, f64::NAN);
+        assert_bit_ne!(f64::NAN, -f64::NAN);
+        assert_bit_ne!(f64::NAN, f64::from_bits(f64::NAN.to_bits() | 1));
+    }
+
+    #[test]
+    fn test_adjacent() {
+        assert_bit_ne!(1.0f64, f64::from_bits(1.0f64.to_bits() + 1));
+    }
+}
+
+mod test_int {
+    #[test]
+    fn test_basic() {
+        assert_bit_eq!(1u8, 1u8);
+        assert_bit_ne!(1u8, 2u8);
+        assert_bit_eq!(-1i64, -1i64);
+        assert_bit_ne!(-1i64, 1i64);
+    }
+}
+
+mod test_ref {
+    #[test]
+    fn test_basic() {
+        assert_bit_eq!(&1.0f32, &1.0f32);
+        assert_bit_ne!(&1.0f32, &2.0f32);
+        assert_bit_eq!(&mut 1.0f64, &mut 1.0f64);
+        assert_bit_ne!(&mut 1.0f64, &mut 2.0f64);
+    }
+}
+
+mod test_cell {
+    use std::cell::{Cell, RefCell};
+
+    #[test]
+    fn test_basic() {
+        assert_bit_eq!(Cell::new(1.0f32), Cell::new(1.0f32));
+        assert_bit_ne!(Cell::new(0.0f32), Cell::new(-0.0f32));
+        assert_bit_eq!(RefCell::new(1.0f64), RefCell::new(1.0f64));
+        assert_bit_ne!(RefCell::new(0.0f64), RefCell::new(-0.0f64));
+    }
+}
+
+mod test_slice {
+    use std::f64;
+
+    #[test]
+    fn test_basic() {
+        assert_bit_eq!([1.0f64, f64::NAN][..], [1.0f64, f64::NAN][..]);
+        assert_bit_ne!([1.0f64, 0.0f64][..], [1.0f64, -0.0f64][..]);
+        assert_bit_ne!([1.0f64, 2.0f64][..], [1.0f64][..]);
+    }
+}
+
+mod test_bits {
+    use approx::Bits;
+
+    #[test]
+    fn test_format() {
+        assert_eq!(format!("{:?}", Bits(&1.0f32)), "0x3f800000");
+        assert_eq!(format!("{:?}", Bits(&-0.0f64)), "0x8000000000000000");
+        assert_eq!(format!("{:?}", Bits(&-1i16)), "0xffff");
+        assert_eq!(format!("{:?}", Bits(&[0u8, 255u8][..])), "[0x00, 0xff]");
+    }
+
+    #[test]
+    #[should_panic(expected = "left bits  = 0x0000000000000000")]
+    fn test_panic_message() {
+        assert_bit_eq!(0.0f64, -0.0f64);
+    }
+}
+
+#[cfg(feature = "num-complex")]
+mod test_complex {
+    extern crate num_complex;
+    use self::num_complex::Complex;
+    use approx::Bits;
+
+    #[test]
+    fn test_basic() {
+        assert_bit_eq!(Complex::new(1.0f32, 2.0f32), Complex::new(1.0f32, 2.0f32));
+        assert_bit_ne!(Complex::new(1.0f32, 0.0f32), Complex::new(1.0f32, -0.0f32));
+        assert_eq!(
+            format!("{:?}", Bits(&Complex::new(1.0f32, 2.0f32))),
+            "Complex { re: 0x3f800000, im: 0x40000000 }",
+        );
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+mod test_ordered_float {
+    extern crate ordered_float;
+    use self::ordered_float::{NotNan, OrderedFloat};
+
+    #[test]
+    fn test_basic() {
+        assert_bit_eq!(OrderedFloat(1.0f64), OrderedFloat(1.0f64));
+        assert_bit_ne!(OrderedFloat(0.0f64), OrderedFloat(-0.0f64));
+        assert_bit_eq!(OrderedFloat(1.0f64), 1.0f64);
+        assert_bit_eq!(NotNan::new(1.0f32).unwrap(), NotNan::new(1.0f32).unwrap());
+        assert_bit_ne!(NotNan::new(0.0f32).unwrap(), -0.0f32);
+    }
+}
@@ -0,0 +1,247 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+
+mod test_comparator {
+    use approx::{AbsDiff, And, Comparator, Not, Or, Relative, Ulps};
+
+    #[test]
+    fn test_basic() {
+        assert!(AbsDiff::default().compare(&1.0f64, &1.0));
+        assert!(!AbsDiff::default().compare(&1.0f64, &1.1));
+        assert!(Relative::default().max_relative(0.2).compare(&1.0f64, &1.1));
+        assert!(Ulps::default().compare(&1.0f32, &1.0000001));
+    }
+
+    #[test]
+    fn test_or() {
+        let within = Or(
+            AbsDiff::default().epsilon(1e-12),
+            Ulps::default().max_ulps(4),
+        );
+        assert!(within.compare(&1e-13f64, &0.0));
+        assert!(within.compare(&1e10f64, &(1e10 + 2e-6)));
+        assert!(!within.compare(&1e-11f64, &0.0));
+        assert!(!within.compare(&1e10f64, &(1e10 + 1e-4)));
+    }
+
+    #[test]
+    fn test_and() {
+        let within = And(
+            Relative::default().epsilon(0.0).max_relative(1e-6),
+            AbsDiff::default().epsilon(1e-3),
+        );
+        assert!(within.compare(&1.0f64, &1.0000001));
+        assert!(!within.compare(&1e4f64, &(1e4 + 5e-3)));
+        assert!(!within.compare(&1e-9f64, &1e-10));
+    }
+
+    #[test]
+    fn test_not() {
+        assert!(Not(AbsDiff::default()).compare(&1.0f64, &2.0));
+        assert!(!Not(AbsDiff::default()).compare(&1.0f64, &1.0));
+    }
+
+    #[test]
+    fn test_methods() {
+        let within = AbsDiff::default()
+            .epsilon(1.0)
+            .and(Relative::default().max_relative(0.5))
+            .and(Ulps::default().max_ulps(0).not());
+        assert!(within.compare(&1.0f64, &1.5));
+        assert!(!within.compare(&100.0f64, &200.0));
+        assert!(!within.compare(&1.0f64, &1.0));
+    }
+
+    #[test]
+    fn test_ref() {
+        let within = AbsDiff::default().epsilon(0.5);
+        let by_ref = &within;
+        assert!(by_ref.compare(&1.0f64, &1.4));
+        assert!((&within).or(Ulps::default()).compare(&1.0f64, &1.4));
+    }
+
+    #[test]
+    fn test_slice() {
+        let within = Or(
+            AbsDiff::default().epsilon(1e-12),
+            Ulps::default().max_ulps(4),
+        );
+        assert!(within.compare(&[1e-13f64, -1e-13][..], &[0.0, 0.0][..]));
+        assert!(within.compare(&[1e10f64, 1.0][..], &[1e10 + 2e-6, 1.0][..]));
+        // The combinators apply to whole values, rather than to each element
+        assert!(!within.compare(&[1e-13f64, 1e10][..], &[0.0, 1e10 + 2e-6][..]));
+    }
+}
+
+mod test_macros {
+    #[test]
+    fn test_default() {
+        assert_approx_eq!(1.0f64, 1.0);
+        assert_approx_ne!(1.0f64, 1.0 + 1e-9);
+    }
+
+    #[test]
+    fn test_any() {
+        assert_approx_eq!(1e-13f64, 0.0, abs = 1e-12, ulps = 4);
+        assert_approx_eq!(1e10f64, 1e10 + 2e-6, abs = 1e-12, ulps = 4);
+        assert_approx_ne!(1e-11f64, 0.0, abs = 1e-12, ulps = 4);
+        assert_approx_eq!(1e10f64, 1e10 + 2e-6, abs = 1e-12, ulps = 4, mode = any);
+        assert_approx_eq!(1e10f64, 1e10 + 2e-6, mode = any, abs = 1e-12, ulps = 4,);
+    }
+
+    #[test]
+    fn test_all() {
+        assert_approx_eq!(1.0f64, 1.0000001, rel = 1e-6, abs = 1e-3, mode = all);
+        assert_approx_ne!(1e4f64, 1e4 + 5e-3, rel = 1e-6, abs = 1e-3, mode = all);
+        assert_approx_ne!(1e-9f64, 1e-10, mode = all, rel = 1e-6, abs = 1e-3);
+        assert_approx_eq!(1e-9f64, 1e-10, rel = 1e-6, abs = 1e-3);
+    }
+
+    #[test]
+    fn test_single() {
+        assert_approx_eq!(1.0f32, 1.5, abs = 0.5);
+        assert_approx_ne!(1e-20f64, 0.0, rel = 0.5);
+        assert_approx_ne!(1e-300f64, -1e-300, ulps = 4);
+        assert_approx_eq!(1.0f64, 1.0, mode = all);
+    }
+
+    #[test]
+    #[should_panic(expected = "assert_approx_eq!(1e-11f64, 0.0, abs = 1e-12, ulps = 4)")]
+    fn test_panic_eq() {
+        assert_approx_eq!(1e-11f64, 0.0, abs = 1e-12, ulps = 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_panic_ne() {
+        assert_approx_ne!(1.0f64, 1.0000001, rel = 1e-6, abs = 1e-3, mode = all);
+    }
+}
+
+mod test_reuse {
+    use approx::{AbsDiff, Relative, Ulps};
+
+    struct Checker {
+        within: Relative<f64>,
+    }
+
+    #[test]
+    fn test_field() {
+        let checker = Checker {
+            within: Relative::default().max_relative(1e-6),
+        };
+        for &x in &[1.0, 1e10, 1e-10] {
+            assert!(checker.within.eq(&x, &(x * (1.0 + 1e-7))));
+            assert!(checker.within.ne(&x, &(x * (1.0 + 1e-5))));
+        }
+    }
+
+    #[test]
+    fn test_traits() {
+        let within = AbsDiff::<f64>::default().epsilon(0.5);
+        let copied = within;
+        assert_eq!(within, copied);
+        assert_eq!(within.clone(), AbsDiff::default().epsilon(0.5));
+        assert!(within != AbsDiff::default());
+        assert_eq!(format!("{:?}", within), "AbsDiff { epsilon: 0.5 }");
+        assert_eq!(
+            format!("{:?}", Ulps::<f32>::default().max_ulps(2)),
+            format!("Ulps {{ epsilon: {:?}, max_ulps: 2 }}", f32::EPSILON),
+        );
+    }
+
+    #[test]
+    fn test_predicate() {
+        let within = Relative::default().max_relative(1e-6);
+        let lhs = [1.0f64, 1e10, 1e-10];
+        let rhs = [1.0000001f64, 1.0000001e10, 1.0000001e-10];
+        assert!(lhs.iter().zip(&rhs).all(within.predicate()));
+        assert!(!lhs.iter().zip(&[1.0, 1e10, 2e-10]).all(within.predicate()));
+    }
+
+    #[test]
+    fn test_eq_to() {
+        let within = AbsDiff::default().epsilon(0.1);
+        let mut values = vec![0.95f64, 1.05, 1.2, 0.8, 1.0];
+        values.retain(within.eq_to(&1.0));
+        assert_eq!(values, [0.95, 1.05, 1.0]);
+        assert_eq!(
+            [1.5f64, 2.0, 2.05]
+                .iter()
+                .filter(|x| within.eq_to(&2.0)(x))
+                .count(),
+            2
+        );
+    }
+}
+
+mod test_epsilon_without_clone {
+    use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+    #[derive(Debug, PartialEq)]
+    struct Meters(f64);
+
+    /// A tolerance that is neither `Clone` nor `Copy`.
+    #[derive(Debug)]
+    struct Margin(f64);
+
+    impl AbsDiffEq for Meters {
+        type Epsilon = Margin;
+
+        fn default_epsilon() -> Margin {
+            Margin(f64::default_epsilon())
+        }
+
+        fn abs_diff_eq(&self, other: &Meters, epsilon: Margin) -> bool {
+            self.0.abs_diff_eq(&other.0, epsilon.0)
+        }
+    }
+
+    impl RelativeEq for Meters {
+        fn default_max_relative() -> Margin {
+            Margin(f64::default_max_relative())
+        }
+
+        fn relative_eq(&self, other: &Meters, epsilon: Margin, max_relative: Margin) -> bool {
+            self.0.relative_eq(&other.0, epsilon.0, max_relative.0)
+        }
+    }
+
+    impl UlpsEq for Meters {
+        fn default_max_ulps() -> u32 {
+            f64::default_max_ulps()
+        }
+
+        fn ulps_eq(&self, other: &Meters, epsilon: Margin, max_ulps: u32) -> bool {
+            self.0.ulps_eq(&other.0, epsilon.0, max_ulps)
+        }
+    }
+
+    #[test]
+    fn test_macros() {
+        assert!(abs_diff_eq!(
+            Meters(1.0),
+            Meters(1.05),
+            epsilon = Margin(0.1)
+        ));
+        assert!(relative_ne!(Meters(1.0), Meters(1.1)));
+        assert!(ulps_eq!(Meters(1.0), Meters(1.0), max_ulps = 0));
+        assert_abs_diff_eq!(Meters(1.0), Meters(1.05), epsilon = Margin(0.1));
+        assert_relative_eq!(Meters(1.0), Meters(1.1), max_relative = Margin(0.2));
+        assert_ulps_ne!(Meters(1.0), Meters(1.1));
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "std")]
+
+// The tests in this file override the defaults explicitly, so that they are not affected by the
+// environment variables that `test_from_env` sets.
+
+#[macro_use]
+extern crate approx;
+
+use approx::{with_defaults, AbsDiff, Defaults, Relative, Ulps};
+use std::env;
+use std::panic;
+use std::{f32, f64};
+
+fn scaled(scale: f64) -> Defaults {
+    Defaults {
+        scale,
+        ..Defaults::default()
+    }
+}
+
+mod test_with_defaults {
+    use super::*;
+
+    #[test]
+    fn test_scale() {
+        with_defaults(scaled(10.0), || {
+            assert_eq!(AbsDiff::<f64>::default().epsilon, 10.0 * f64::EPSILON);
+            assert_eq!(Relative::<f32>::default().max_relative, 10.0 * f32::EPSILON);
+            assert_abs_diff_eq!(1.0, 1.0 + 8.0 * f64::EPSILON);
+            assert_relative_eq!(&[1.0, 2.0][..], &[1.0, 2.0 + 16.0 * f64::EPSILON][..]);
+        });
+        with_defaults(Defaults::default(), || {
+            assert_abs_diff_ne!(1.0, 1.0 + 8.0 * f64::EPSILON);
+        });
+    }
+
+    #[test]
+    fn test_max_ulps() {
+        let defaults = Defaults {
+            max_ulps: Some(8),
+            ..Defaults::default()
+        };
+        with_defaults(defaults, || {
+            assert_eq!(Ulps::<f64>::default().max_ulps, 8);
+            assert_ulps_eq!(1.0, 1.0 + 6.0 * f64::EPSILON, epsilon = 0.0);
+            assert_ulps_ne!(1.0, 1.0 + 6.0 * f64::EPSILON, epsilon = 0.0, max_ulps = 4);
+        });
+    }
+
+    #[test]
+    fn test_replaced() {
+        let defaults = Defaults {
+            epsilon: Some(1e-12),
+            max_relative: Some(1e-6),
+            ..Defaults::default()
+        };
+        with_defaults(defaults, || {
+            assert_eq!(
+                Relative::<f64>::default(),
+                Relative::default().epsilon(1e-12).max_relative(1e-6)
+            );
+            assert_eq!(Ulps::<f32>::default().epsilon, 1e-12);
+            assert_relative_eq!(1e6, 1e6 + 0.5);
+            assert_abs_diff_ne!(0.0, 1e-11);
+        });
+        with_defaults(
+            Defaults {
+                scale: 2.0,
+                ..defaults
+            },
+            || {
+                assert_eq!(Relative::<f64>::default().max_relative, 2e-6);
+            },
+        );
+    }
+
+    #[test]
+    fn test_explicit_options() {
+        with_defaults(scaled(1e6), || {
+            assert_abs_diff_ne!(1.0, 1.0 + 8.0 * f64::EPSILON, epsilon = f64::EPSILON);
+            assert_eq!(
+                Relative::<f64>::default().max_relative(1e-9).max_relative,
+                1e-9
+            );
+        });
+    }
+
+    #[test]
+    fn test_nested() {
+        with_defaults(scaled(2.0), || {
+            with_defaults(scaled(4.0), || assert_eq!(Defaults::current().scale, 4.0));
+            assert_eq!(Defaults::current().scale, 2.0);
+        });
+    }
+
+    #[test]
+    fn test_restored_after_panic() {
+        with_defaults(Defaults::default(), || {
+            let result = panic::catch_unwind(|| with_defaults(scaled(2.0), || panic!()));
+            assert!(result.is_err());
+            assert_eq!(Defaults::current(), Defaults::default());
+        });
+    }
+
+    #[test]
+    fn test_threads() {
+        with_defaults(scaled(2.0), || {
+            let other = std::thread::spawn(|| with_defaults(scaled(3.0), Defaults::current));
+            assert_eq!(other.join().unwrap().scale, 3.0);
+            assert_eq!(Defaults::current().scale, 2.0);
+        });
+    }
+}
+
+mod test_failure_message {
+    use super::*;
+
+    fn message<F: FnOnce() + panic::UnwindSafe>(f: F) -> String {
+        let payload = panic::catch_unwind(f).unwrap_err();
+        payload.downcast::<String>().map(|s| *s).unwrap()
+    }
+
+    #[test]
+    fn test_overridden() {
+        let defaults = Defaults {
+            scale: 10.0,
+            epsilon: None,
+            max_relative: Some(1e-6),
+            max_ulps: Some(8),
+        };
+        let message = message(|| with_defaults(defaults, || assert_relative_eq!(1.0, 2.0)));
+        // `max_ulps` is not used by relative comparisons.
+        assert!(message.ends_with("right = 2.0\n    defaults: scale = 10, max_relative = 1e-6\n\n"));
+    }
+
+    #[test]
+    fn test_not_applied() {
+        // Does not forward `scale_epsilon` or `epsilon_from_f64`, so the overrides do not apply.
+        #[derive(Debug)]
+        struct Opaque(f64);
+
+        impl approx::AbsDiffEq for Opaque {
+            type Epsilon = f64;
+
+            fn default_epsilon() -> f64 {
+                f64::EPSILON
+            }
+
+            fn abs_diff_eq(&self, other: &Opaque, epsilon: f64) -> bool {
+                (self.0 - other.0).abs() <= epsilon
+            }
+        }
+
+        with_defaults(scaled(1e6), || {
+            assert_eq!(AbsDiff::<Opaque>::default().epsilon, f64::EPSILON);
+        });
+        let message = message(|| {
+            with_defaults(scaled(1e6), || {
+                assert_abs_diff_eq!(Opaque(1.0), Opaque(1.0 + 1e-12))
+            })
+        });
+        assert!(message.ends_with("right = Opaque(1.000000000001)\n\n"));
+        assert!(!message.contains("defaults"));
+    }
+
+    #[test]
+    fn test_not_overridden() {
+        let message =
+            message(|| with_defaults(Defaults::default(), || assert_relative_eq!(1.0, 2.0)));
+        assert!(message.ends_with("right = 2.0\n\n"));
+        assert!(!message.contains("defaults"));
+    }
+}
+
+#[test]
+fn test_from_env() {
+    // The overrides are read once for each thread, so each case runs on a new thread.
+    env::set_var("APPROX_TOLERANCE_SCALE", "10");
+    env::set_var("APPROX_MAX_ULPS", " 8 ");
+    let defaults = Defaults::from_env();
+    let read = std::thread::spawn(|| {
+        assert_relative_eq!(1.0, 1.0 + 8.0 * f64::EPSILON);
+        (Defaults::current(), Relative::<f64>::default())
+    })
+    .join();
+    let overridden = std::thread::spawn(|| with_defaults(scaled(2.0), Defaults::current)).join();
+    env::set_var("APPROX_TOLERANCE_SCALE", "1o");
+    let invalid = Defaults::from_env();
+    let panicked = std::thread::spawn(Relative::<f64>::default).join();
+    env::remove_var("APPROX_TOLERANCE_SCALE");
+    env::remove_var("APPROX_MAX_ULPS");
+
+    let expected = Defaults {
+        scale: 10.0,
+        max_ulps: Some(8),
+        ..Defaults::default()
+    };
+    assert_eq!(defaults, Ok(expected));
+    assert_eq!(Defaults::from_env(), Ok(Defaults::default()));
+
+    // The constructors read the environment unless the overrides are replaced.
+    let (current, relative) = read.unwrap();
+    assert_eq!(current, expected);
+    assert_eq!(relative.max_relative, 10.0 * f64::EPSILON);
+    assert_eq!(overridden.unwrap(), scaled(2.0));
+
+    // Invalid values are reported by `from_env` and make the constructors panic.
+    let error = invalid.unwrap_err();
+    assert_eq!(error.name(), "APPROX_TOLERANCE_SCALE");
+    assert_eq!(error.value(), "1o");
+    assert_eq!(
+        error.to_string(),
+        "invalid APPROX_TOLERANCE_SCALE `1o`: expected a non-negative number"
+    );
+    let message = panicked.unwrap_err().downcast::<String>().unwrap();
+    assert_eq!(*message, error.to_string());
+}
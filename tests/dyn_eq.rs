@@ -0,0 +1,160 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+
+use approx::{AbsDiffEq, DynApproxEq, Exact, RelativeEq, Tolerance, UlpsEq};
+use std::f64;
+
+#[derive(Debug, PartialEq)]
+struct Celsius(f64);
+
+impl AbsDiffEq for Celsius {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Celsius, epsilon: f64) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon)
+    }
+}
+
+impl RelativeEq for Celsius {
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Celsius, epsilon: f64, max_relative: f64) -> bool {
+        self.0.relative_eq(&other.0, epsilon, max_relative)
+    }
+}
+
+impl UlpsEq for Celsius {
+    fn default_max_ulps() -> u32 {
+        f64::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Celsius, epsilon: f64, max_ulps: u32) -> bool {
+        self.0.ulps_eq(&other.0, epsilon, max_ulps)
+    }
+}
+
+fn boxed() -> Vec<Box<dyn DynApproxEq>> {
+    let mut values: Vec<Box<dyn DynApproxEq>> = vec![
+        Box::new(1.0f64),
+        Box::new(1.0f32),
+        Box::new(Celsius(20.0)),
+        Box::new(Exact(3u8)),
+    ];
+    values.extend(strings("abc"));
+    values
+}
+
+/// The value as a `String`, which only implements the comparisons with the `std` feature.
+#[cfg(feature = "std")]
+fn strings(value: &str) -> Vec<Box<dyn DynApproxEq>> {
+    vec![Box::new(value.to_string())]
+}
+
+#[cfg(not(feature = "std"))]
+fn strings(_: &str) -> Vec<Box<dyn DynApproxEq>> {
+    Vec::new()
+}
+
+mod test_dyn_approx_eq {
+    use super::*;
+
+    #[test]
+    fn test_same_types() {
+        let tolerance = Tolerance::Relative {
+            epsilon: 0.0,
+            max_relative: 1e-6,
+        };
+        let mut values: Vec<Box<dyn DynApproxEq>> = vec![
+            Box::new(1.0f64 + 1e-9),
+            Box::new(1.0f32 + 1e-9),
+            Box::new(Celsius(20.0 + 1e-9)),
+            Box::new(Exact(3u8)),
+        ];
+        values.extend(strings("abc"));
+        for (a, b) in boxed().iter().zip(&values) {
+            assert!(a.dyn_approx_eq(&**b, &tolerance));
+        }
+    }
+
+    #[test]
+    fn test_different_values() {
+        let tolerance = Tolerance::Abs { epsilon: 0.5 };
+        assert!(1.0f64.dyn_approx_eq(&1.4f64, &tolerance));
+        assert!(1.0f64.dyn_approx_ne(&1.6f64, &tolerance));
+        assert!(Celsius(20.0).dyn_approx_ne(&Celsius(21.0), &tolerance));
+        assert!(Celsius(20.0).dyn_approx_eq(&Celsius(20.25), &tolerance));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_strings() {
+        let tolerance = Tolerance::Abs { epsilon: 0.5 };
+        assert!("abc"
+            .to_string()
+            .dyn_approx_eq(&"abc".to_string(), &tolerance));
+        assert!("abc"
+            .to_string()
+            .dyn_approx_ne(&"abd".to_string(), &tolerance));
+    }
+
+    #[test]
+    fn test_type_mismatch() {
+        let values = boxed();
+        for (i, a) in values.iter().enumerate() {
+            for (j, b) in values.iter().enumerate() {
+                assert_eq!(a.dyn_approx_eq(&**b, &Tolerance::Exact), i == j);
+            }
+        }
+        assert!(1.0f64.dyn_approx_ne(&Celsius(1.0), &Tolerance::Exact));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_tolerances() {
+        let (a, b) = (1.0f64, 1.0 + 2.0 * f64::EPSILON);
+        assert!(a.dyn_approx_ne(&b, &Tolerance::Exact));
+        assert!(a.dyn_approx_eq(
+            &b,
+            &Tolerance::Ulps {
+                epsilon: 0.0,
+                max_ulps: 2
+            }
+        ));
+        assert!(a.dyn_approx_eq(
+            &b,
+            &Tolerance::Any(vec![Tolerance::Exact, Tolerance::Abs { epsilon: 1e-12 }])
+        ));
+        assert!(a.dyn_approx_ne(
+            &b,
+            &Tolerance::All(vec![Tolerance::Exact, Tolerance::Abs { epsilon: 1e-12 }])
+        ));
+    }
+
+    #[test]
+    fn test_downcast() {
+        let values = boxed();
+        assert_eq!(
+            values[2].as_any().downcast_ref::<Celsius>(),
+            Some(&Celsius(20.0))
+        );
+    }
+}
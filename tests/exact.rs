@@ -0,0 +1,147 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+
+use approx::{AbsDiffEq, Exact, Relative, RelativeEq, UlpsEq};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Axis {
+    X,
+    Y,
+}
+
+#[derive(Debug, PartialEq)]
+struct Measurement {
+    label: String,
+    axis: Exact<Axis>,
+    valid: bool,
+    value: f64,
+}
+
+impl AbsDiffEq for Measurement {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Measurement, epsilon: f64) -> bool {
+        self.label.abs_diff_eq(&other.label, ())
+            && self.axis.abs_diff_eq(&other.axis, ())
+            && self.valid.abs_diff_eq(&other.valid, ())
+            && self.value.abs_diff_eq(&other.value, epsilon)
+    }
+}
+
+fn measurement(axis: Axis, value: f64) -> Measurement {
+    Measurement {
+        label: "a".to_string(),
+        axis: Exact(axis),
+        valid: true,
+        value,
+    }
+}
+
+fn all_relative_eq<T: RelativeEq + ?Sized>(a: &T, b: &T) -> bool {
+    T::relative_eq(a, b, T::default_epsilon(), T::default_max_relative())
+}
+
+mod test_primitives {
+    use super::*;
+
+    #[test]
+    fn test_bool() {
+        assert_abs_diff_eq!(true, true);
+        assert_abs_diff_ne!(true, false);
+        assert_relative_eq!(false, false);
+        assert_relative_ne!(false, true);
+        assert_ulps_eq!(true, true);
+        assert_ulps_ne!(true, false);
+    }
+
+    #[test]
+    fn test_char() {
+        assert_abs_diff_eq!('a', 'a');
+        assert_relative_ne!('a', 'b');
+        assert_ulps_ne!('a', 'A');
+    }
+
+    #[test]
+    fn test_str() {
+        assert_abs_diff_eq!("abc", "abc");
+        assert_relative_ne!("abc", "abd");
+        assert_ulps_eq!(*"abc", *"abc");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_string() {
+        assert_abs_diff_eq!("abc".to_string(), "abc".to_string());
+        assert_relative_ne!("abc".to_string(), "ab".to_string());
+        assert_ulps_eq!("abc".to_string(), "abc".to_string());
+    }
+
+    #[test]
+    fn test_slices() {
+        assert_relative_eq!([true, false][..], [true, false][..]);
+        assert_relative_ne!(['a', 'b'][..], ['a', 'c'][..]);
+    }
+
+    #[test]
+    fn test_generic() {
+        assert!(all_relative_eq(&true, &true));
+        assert!(all_relative_eq("abc", "abc"));
+        assert!(all_relative_eq(&1.0, &1.0));
+        assert!(!all_relative_eq(&'a', &'b'));
+        assert!(Relative::default().eq(&true, &true));
+        assert!(UlpsEq::ulps_eq(&'a', &'a', (), 0));
+    }
+}
+
+mod test_exact {
+    use super::*;
+
+    #[test]
+    fn test_macros() {
+        assert_abs_diff_eq!(Exact(Axis::X), Exact(Axis::X));
+        assert_abs_diff_ne!(Exact(Axis::X), Exact(Axis::Y));
+        assert_relative_eq!(Exact(Axis::Y), Exact(Axis::Y));
+        assert_ulps_ne!(Exact(Axis::Y), Exact(Axis::X));
+    }
+
+    #[test]
+    fn test_debug() {
+        assert_eq!(format!("{:?}", Exact(Axis::X)), "X");
+    }
+
+    #[test]
+    fn test_composite() {
+        assert_abs_diff_eq!(
+            measurement(Axis::X, 1.0),
+            measurement(Axis::X, 1.05),
+            epsilon = 0.1
+        );
+        assert_abs_diff_ne!(
+            measurement(Axis::X, 1.0),
+            measurement(Axis::Y, 1.0),
+            epsilon = 0.1
+        );
+
+        let mut invalid = measurement(Axis::X, 1.0);
+        invalid.valid = false;
+        assert_abs_diff_ne!(measurement(Axis::X, 1.0), invalid);
+    }
+}
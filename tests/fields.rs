@@ -0,0 +1,164 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "std")]
+
+#[macro_use]
+extern crate approx;
+
+use approx::{ApproxFields, ByFields, Fields, Relative, Ulps};
+use std::f64;
+
+#[derive(Debug, PartialEq)]
+struct Vector {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+#[derive(Debug, PartialEq)]
+struct Pose {
+    translation: Vector,
+    rotation: [f64; 2],
+    angle: f64,
+}
+
+impl ApproxFields for Vector {
+    type Scalar = f64;
+
+    fn visit_fields(&self, other: &Vector, fields: &mut Fields<f64>) {
+        fields.field("x", &self.x, &other.x);
+        fields.field("y", &self.y, &other.y);
+        fields.field("z", &self.z, &other.z);
+    }
+}
+
+impl ApproxFields for Pose {
+    type Scalar = f64;
+
+    fn visit_fields(&self, other: &Pose, fields: &mut Fields<f64>) {
+        fields.nested("translation", &self.translation, &other.translation);
+        fields.field("rotation", &self.rotation[..], &other.rotation[..]);
+        fields.field("angle", &self.angle, &other.angle);
+    }
+}
+
+fn pose(x: f64, angle: f64) -> Pose {
+    Pose {
+        translation: Vector { x, y: 2.0, z: 3.0 },
+        rotation: [0.0, 1.0],
+        angle,
+    }
+}
+
+mod test_field_mismatches {
+    use super::*;
+
+    #[test]
+    fn test_paths() {
+        let tolerance = Relative::<f64>::default().into();
+        let mismatches = approx::field_mismatches(&pose(1.0, 0.5), &pose(1.5, 0.25), &tolerance);
+
+        let paths: Vec<_> = mismatches.iter().map(|m| &m.path[..]).collect();
+        assert_eq!(paths, ["translation.x", "angle"]);
+        assert_eq!(mismatches[0].left, "1.0");
+        assert_eq!(mismatches[0].right, "1.5");
+        assert_eq!(mismatches[1].to_string(), "angle: left = 0.5, right = 0.25");
+    }
+
+    #[test]
+    fn test_equal() {
+        let tolerance = Relative::<f64>::default().into();
+        let mismatches = approx::field_mismatches(&pose(1.0, 0.5), &pose(1.0, 0.5), &tolerance);
+        assert!(mismatches.is_empty());
+    }
+
+    #[test]
+    fn test_tolerance() {
+        let tolerance = Ulps::<f64>::default().max_ulps(8).into();
+        let (a, b) = (pose(1.0, 0.5), pose(1.0 + 4.0 * f64::EPSILON, 0.5));
+        assert!(approx::field_mismatches(&a, &b, &tolerance).is_empty());
+    }
+}
+
+mod test_by_fields {
+    use super::*;
+
+    #[test]
+    fn test_macros() {
+        assert_abs_diff_eq!(
+            ByFields(pose(1.0, 0.5)),
+            ByFields(pose(1.1, 0.5)),
+            epsilon = 0.2
+        );
+        assert_abs_diff_ne!(ByFields(pose(1.0, 0.5)), ByFields(pose(1.1, 0.5)));
+        assert_relative_eq!(
+            ByFields(pose(1.0, 0.5)),
+            ByFields(pose(1.0 + f64::EPSILON, 0.5))
+        );
+        assert_relative_ne!(ByFields(pose(1.0, 0.5)), ByFields(pose(1.0, 0.6)));
+        assert_ulps_eq!(ByFields(pose(1.0, 0.5)), ByFields(pose(1.0, 0.5)));
+        assert_ulps_ne!(ByFields(pose(1.0, 0.5)), ByFields(pose(2.0, 0.5)));
+    }
+
+    #[test]
+    fn test_debug() {
+        assert_eq!(
+            format!(
+                "{:?}",
+                ByFields(Vector {
+                    x: 1.0,
+                    y: 2.0,
+                    z: 3.0
+                })
+            ),
+            "Vector { x: 1.0, y: 2.0, z: 3.0 }"
+        );
+    }
+}
+
+mod test_assert_fields {
+    use super::*;
+
+    #[test]
+    fn test_macros() {
+        assert_fields_eq!(pose(1.0, 0.5), pose(1.0 + f64::EPSILON, 0.5));
+        assert_fields_eq!(pose(1.0, 0.5), pose(1.1, 0.5), max_relative = 0.1);
+        assert_fields_eq!(pose(1.0, 0.5), pose(1.1, 0.5), max_relative = 0.1,);
+        assert_fields_ne!(pose(1.0, 0.5), pose(1.0, 0.6));
+        assert_fields_ne!(pose(1.0, 0.5), pose(1.1, 0.5), max_relative = 0.01);
+    }
+
+    #[test]
+    #[should_panic(expected = "
+    mismatched fields:
+        translation.x: left = 1.0, right = 1.5
+        angle: left = 0.5, right = 0.25
+")]
+    fn test_report() {
+        assert_fields_eq!(pose(1.0, 0.5), pose(1.5, 0.25));
+    }
+
+    #[test]
+    #[should_panic(expected = "assert_fields_eq!(pose(1.0, 0.5), pose(1.5, 0.5), epsilon = 0.1)")]
+    fn test_report_options() {
+        assert_fields_eq!(pose(1.0, 0.5), pose(1.5, 0.5), epsilon = 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "assert_fields_ne!(pose(1.0, 0.5), pose(1.0, 0.5))")]
+    fn test_ne() {
+        assert_fields_ne!(pose(1.0, 0.5), pose(1.0, 0.5));
+    }
+}
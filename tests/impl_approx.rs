@@ -0,0 +1,127 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+
+use std::f64;
+use std::marker::PhantomData;
+
+#[derive(Debug, PartialEq)]
+struct Point<T> {
+    x: T,
+    y: T,
+    z: T,
+}
+
+impl_approx!(Point<T> { x, y, z, });
+
+#[derive(Debug, PartialEq)]
+struct Pair<T>(T, T);
+
+impl_approx!(Pair<T>(0, 1));
+
+#[derive(Debug, PartialEq)]
+struct Quantity<T, U> {
+    value: T,
+    unit: PhantomData<U>,
+}
+
+impl_approx!(Quantity < T, U > { value });
+
+#[derive(Debug, PartialEq)]
+struct Seconds(f64);
+
+impl_approx!(Seconds(0) as f64);
+
+#[derive(Debug, PartialEq)]
+struct Ray {
+    origin: Point<f32>,
+    direction: Point<f32>,
+}
+
+impl_approx!(Ray { origin, direction } as f32);
+
+fn point<T>(x: T, y: T, z: T) -> Point<T> {
+    Point { x, y, z }
+}
+
+mod test_generic {
+    use super::*;
+
+    #[test]
+    fn test_named() {
+        assert_abs_diff_eq!(point(1.0, 2.0, 3.0), point(1.0, 2.0, 3.0));
+        assert_abs_diff_ne!(point(1.0, 2.0, 3.0), point(1.0, 2.0, 3.1));
+        assert_relative_eq!(
+            point(1.0, 2.0, 3.0),
+            point(1.0, 2.0, 3.3),
+            max_relative = 0.1
+        );
+        assert_relative_ne!(point(1.0f32, 2.0, 3.0), point(1.0, 2.1, 3.0));
+        assert_ulps_eq!(
+            point(1.0, 2.0, 3.0),
+            point(1.0, 2.0, 3.0 + 2.0 * f64::EPSILON)
+        );
+        assert_ulps_ne!(point(1.0, 2.0, 3.0), point(1.0 + 1e-12, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_tuple() {
+        assert_abs_diff_eq!(Pair(1.0, 2.0), Pair(1.5, 2.5), epsilon = 0.5);
+        assert_relative_ne!(Pair(1.0, 2.0), Pair(1.0, 2.5), max_relative = 0.1);
+        assert_ulps_eq!(Pair(1.0f32, 2.0), Pair(1.0, 2.0));
+    }
+
+    #[test]
+    fn test_extra_parameters() {
+        let quantity = |value| Quantity::<f64, Seconds> {
+            value,
+            unit: PhantomData,
+        };
+        assert_relative_eq!(quantity(1.0), quantity(1.0 + 1e-9), max_relative = 1e-6);
+        assert_ulps_ne!(quantity(1.0), quantity(1.1));
+    }
+
+    #[test]
+    fn test_defaults() {
+        use approx::AbsDiffEq;
+
+        assert_eq!(Point::<f32>::default_epsilon(), f32::EPSILON);
+        assert_eq!(Pair::<f64>::scale_epsilon(&1.0, 10.0), Some(10.0));
+        assert_eq!(Point::<f64>::epsilon_from_f64(0.5), Some(0.5));
+    }
+}
+
+mod test_concrete {
+    use super::*;
+
+    #[test]
+    fn test_tuple() {
+        assert_abs_diff_eq!(Seconds(1.0), Seconds(1.0));
+        assert_relative_eq!(Seconds(1.0), Seconds(1.01), max_relative = 0.01);
+        assert_ulps_ne!(Seconds(1.0), Seconds(1.01));
+    }
+
+    #[test]
+    fn test_nested() {
+        let ray = |dz: f32| Ray {
+            origin: point(0.0, 0.0, 0.0),
+            direction: point(0.0, 0.0, 1.0 + dz),
+        };
+        assert_abs_diff_eq!(ray(0.0), ray(1e-4), epsilon = 1e-3);
+        assert_relative_ne!(ray(0.0), ray(1e-4));
+        assert_ulps_eq!(ray(0.0), ray(1e-7));
+    }
+}
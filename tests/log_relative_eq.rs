@@ -0,0 +1,150 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "std")]
+
+#[macro_use]
+extern crate approx;
+
+mod test_f32 {
+    #[test]
+    fn test_basic() {
+        assert_log_relative_eq!(1.0f32, 1.0f32);
+        assert_log_relative_ne!(1.0f32, 2.0f32);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_eq() {
+        assert_log_relative_eq!(1.0f32, 2.0f32);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_ne() {
+        assert_log_relative_ne!(1.0f32, 1.0f32);
+    }
+
+    #[test]
+    fn test_extremes() {
+        assert_log_relative_eq!(f32::MAX, f32::MAX * 0.99, max_log_diff = 0.02);
+        assert_log_relative_ne!(f32::MAX, f32::MIN_POSITIVE, max_log_diff = 100.0);
+        assert_log_relative_eq!(1e-45f32, 1e-45f32 * 2.0, max_log_diff = 0.7);
+    }
+}
+
+mod test_f64 {
+    #[test]
+    fn test_basic() {
+        assert_log_relative_eq!(1.0f64, 1.0f64);
+        assert_log_relative_ne!(1.0f64, 2.0f64);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_eq() {
+        assert_log_relative_eq!(1.0f64, 2.0f64);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_ne() {
+        assert_log_relative_ne!(1.0f64, 1.0f64);
+    }
+
+    #[test]
+    fn test_orders_of_magnitude() {
+        assert_log_relative_eq!(1e-300f64, 1.001e-300f64, max_log_diff = 1e-3);
+        assert_log_relative_ne!(1e-300f64, 1.002e-300f64, max_log_diff = 1e-3);
+        assert_log_relative_eq!(1e10f64, 1.001e10f64, max_log_diff = 1e-3);
+        assert_log_relative_ne!(1e10f64, 1.002e10f64, max_log_diff = 1e-3);
+    }
+
+    #[test]
+    fn test_extremes() {
+        assert_log_relative_eq!(f64::MAX, f64::MAX * 0.99, max_log_diff = 0.02);
+        assert_log_relative_ne!(f64::MAX, f64::MIN_POSITIVE, max_log_diff = 1000.0);
+        assert_log_relative_eq!(f64::MAX, f64::MIN_POSITIVE, max_log_diff = 1500.0);
+        assert_log_relative_eq!(5e-324f64, 1e-323f64, max_log_diff = 0.7);
+    }
+
+    #[test]
+    fn test_decibels() {
+        // 0.1 dB corresponds to a power ratio of about 1.0233
+        assert_log_relative_eq!(1.0f64, 1.023f64, max_decibels = 0.1);
+        assert_log_relative_ne!(1.0f64, 1.024f64, max_decibels = 0.1);
+        assert_log_relative_eq!(1e-200f64, 1.023e-200f64, max_decibels = 0.1);
+    }
+
+    #[test]
+    fn test_zero() {
+        assert_log_relative_eq!(0.0f64, 0.0f64);
+        assert_log_relative_eq!(0.0f64, -0.0f64);
+        assert_log_relative_ne!(0.0f64, f64::MIN_POSITIVE, max_log_diff = f64::MAX);
+        assert_log_relative_ne!(f64::MIN_POSITIVE, 0.0f64, max_log_diff = f64::MAX);
+    }
+
+    #[test]
+    fn test_negative() {
+        assert_log_relative_eq!(-1e-300f64, -1.001e-300f64, max_log_diff = 1e-3);
+        assert_log_relative_ne!(-1.0f64, 1.0f64, max_log_diff = f64::MAX);
+        assert_log_relative_ne!(1.0f64, -1.0f64, max_log_diff = f64::MAX);
+    }
+
+    #[test]
+    fn test_infinity() {
+        assert_log_relative_eq!(f64::INFINITY, f64::INFINITY);
+        assert_log_relative_eq!(f64::NEG_INFINITY, f64::NEG_INFINITY);
+        assert_log_relative_ne!(f64::INFINITY, f64::MAX, max_log_diff = f64::MAX);
+        assert_log_relative_ne!(f64::INFINITY, f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_nan() {
+        assert_log_relative_ne!(f64::NAN, f64::NAN, max_log_diff = f64::MAX);
+        assert_log_relative_ne!(f64::NAN, 1.0f64, max_log_diff = f64::MAX);
+        assert_log_relative_ne!(1.0f64, f64::NAN, max_log_diff = f64::MAX);
+    }
+}
+
+mod test_ref {
+    #[test]
+    fn test_basic() {
+        assert_log_relative_eq!(&1.0f64, &1.0f64);
+        assert_log_relative_ne!(&1.0f64, &2.0f64);
+    }
+}
+
+mod test_slice {
+    #[test]
+    fn test_basic() {
+        assert_log_relative_eq!([1e-300f64, 1e10][..], [1e-300f64, 1e10][..]);
+        assert_log_relative_ne!([1e-300f64, 1e10][..], [1e10f64, 1e-300][..]);
+        assert_log_relative_ne!([1e-300f64, 1e10][..], [1e-300f64][..]);
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+mod test_ordered_float {
+    extern crate ordered_float;
+    use self::ordered_float::{NotNan, OrderedFloat};
+
+    #[test]
+    fn test_basic() {
+        assert_log_relative_eq!(OrderedFloat(1e-300f64), OrderedFloat(1e-300f64));
+        assert_log_relative_ne!(OrderedFloat(1e-300f64), OrderedFloat(2e-300f64));
+        assert_log_relative_eq!(OrderedFloat(1e-300f64), 1e-300f64);
+        assert_log_relative_eq!(NotNan::new(1e-300f64).unwrap(), 1e-300f64);
+    }
+}
@@ -97,9 +97,125 @@ fn test_ulps_ne_trailing_commas() {
     let _: bool = ulps_ne!(1.0, 1.0, epsilon = 1.0, max_ulps = 1,);
 }
 
+#[test]
+fn test_approx_eq() {
+    let _: bool = approx_eq!(1.0, 1.0);
+    let _: bool = approx_eq!(1.0, 1.0,);
+    let _: bool = approx_eq!(1.0, 1.0, abs = 1.0);
+    let _: bool = approx_eq!(1.0, 1.0, abs = 1.0, rel = 1.0, ulps = 1);
+    let _: bool = approx_eq!(1.0, 1.0, abs = 1.0, rel = 1.0, ulps = 1, mode = any);
+    let _: bool = approx_eq!(1.0, 1.0, mode = all, abs = 1.0, rel = 1.0,);
+}
+
+#[test]
+fn test_approx_ne() {
+    let _: bool = approx_ne!(1.0, 1.0);
+    let _: bool = approx_ne!(1.0, 1.0, ulps = 1);
+    let _: bool = approx_ne!(1.0, 1.0, abs = 1.0, rel = 1.0, mode = all,);
+}
+
+#[test]
+fn test_approx_zero() {
+    let _: bool = approx_zero!(1.0);
+    let _: bool = approx_zero!(1.0, scale = 1.0);
+    let _: bool = approx_zero!(1.0, epsilon = 1.0, max_relative = 1.0, scale = 1.0);
+    let _: bool = approx_zero!(1.0, scale = 1.0, epsilon = 1.0,);
+}
+
+#[test]
+fn test_approx_nonzero() {
+    let _: bool = approx_nonzero!(1.0);
+    let _: bool = approx_nonzero!(1.0, scale = 1.0);
+    let _: bool = approx_nonzero!(1.0, scale = 1.0,);
+}
+
+#[test]
+fn test_scale() {
+    let _: bool = relative_eq!(1.0, 1.0, scale = 1.0);
+    let _: bool = relative_ne!(1.0, 1.0, epsilon = 1.0, scale = 1.0, max_relative = 1.0);
+    let _: bool = ulps_eq!(1.0, 1.0, scale = 1.0);
+    let _: bool = ulps_ne!(1.0, 1.0, max_ulps = 1, scale = 1.0, epsilon = 1.0);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_log_relative_eq() {
+    let _: bool = log_relative_eq!(1.0, 1.0);
+    let _: bool = log_relative_eq!(1.0, 1.0, max_log_diff = 1.0);
+    let _: bool = log_relative_eq!(1.0, 1.0, max_decibels = 1.0);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_log_relative_eq_trailing_commas() {
+    let _: bool = log_relative_eq!(1.0, 1.0,);
+    let _: bool = log_relative_eq!(1.0, 1.0, max_log_diff = 1.0,);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_log_relative_ne() {
+    let _: bool = log_relative_ne!(1.0, 1.0);
+    let _: bool = log_relative_ne!(1.0, 1.0, max_log_diff = 1.0);
+    let _: bool = log_relative_ne!(1.0, 1.0, max_decibels = 1.0);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_sig_figs_eq() {
+    let _: bool = sig_figs_eq!(1.0, 1.0, sig_figs = 6);
+    let _: bool = sig_figs_eq!(1.0, 1.0, sig_figs = 6,);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_sig_figs_ne() {
+    let _: bool = sig_figs_ne!(1.0, 1.0, sig_figs = 6);
+    let _: bool = sig_figs_ne!(1.0, 1.0, sig_figs = 6,);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_places_eq() {
+    let _: bool = places_eq!(1.0, 1.0, places = 4);
+    let _: bool = places_eq!(1.0, 1.0, places = 4,);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_places_ne() {
+    let _: bool = places_ne!(1.0, 1.0, places = 4);
+    let _: bool = places_ne!(1.0, 1.0, places = 4,);
+}
+
+#[test]
+fn test_angle_eq() {
+    let _: bool = angle_eq!(1.0, 1.0);
+    let _: bool = angle_eq!(1.0, 1.0, epsilon = 1.0);
+    let _: bool = angle_eq!(1.0, 1.0, period = 360.0);
+    let _: bool = angle_eq!(1.0, 1.0, period = 360.0, max_relative = 1.0);
+    let _: bool = angle_eq!(1.0, 1.0, period = 360.0, max_ulps = 1);
+}
+
+#[test]
+fn test_angle_eq_trailing_commas() {
+    let _: bool = angle_eq!(1.0, 1.0,);
+    let _: bool = angle_eq!(1.0, 1.0, epsilon = 1.0, period = 360.0,);
+}
+
+#[test]
+fn test_angle_ne() {
+    let _: bool = angle_ne!(1.0, 1.0);
+    let _: bool = angle_ne!(1.0, 1.0, epsilon = 1.0);
+    let _: bool = angle_ne!(1.0, 1.0, period = 360.0);
+    let _: bool = angle_ne!(1.0, 1.0, period = 360.0, max_relative = 1.0);
+    let _: bool = angle_ne!(1.0, 1.0, period = 360.0, max_ulps = 1);
+}
+
 #[test]
 fn test_rvalue_arguments() {
     assert_abs_diff_eq!(vec![0.0].as_slice(), vec![0.0].as_slice());
     assert_relative_eq!(vec![0.0].as_slice(), vec![0.0].as_slice());
     assert_ulps_eq!(vec![0.0].as_slice(), vec![0.0].as_slice());
+    assert_angle_eq!(vec![0.0].as_slice(), vec![0.0].as_slice());
 }
@@ -0,0 +1,110 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+
+use approx::{AbsDiff, AbsDiffEq, Mixed, Relative, Ulps};
+use std::{f32, f64};
+
+mod test_floats {
+    use super::*;
+
+    #[test]
+    fn test_f32_f64() {
+        let kernel: f32 = 0.1 + 0.2;
+        let reference: f64 = 0.1 + 0.2;
+
+        assert_relative_eq!(Mixed(kernel), reference);
+        assert_relative_eq!(Mixed(reference), kernel);
+        assert_ulps_eq!(Mixed(kernel), 0.3f64, max_ulps = 0);
+        assert_abs_diff_ne!(Mixed(kernel), 0.31f64);
+    }
+
+    #[test]
+    fn test_wrapped_right() {
+        let kernel: f32 = 0.1 + 0.2;
+        let reference: f64 = 0.3;
+
+        assert_relative_eq!(Mixed(reference), Mixed(kernel));
+        assert_ulps_eq!(Mixed(kernel), Mixed(reference), max_ulps = 0);
+        assert_abs_diff_ne!(Mixed(kernel), Mixed(0.31f64));
+        assert!(reference == Mixed(kernel));
+        assert!(1.0f32 != Mixed(1.0 + 1e-6));
+        assert_eq!(Mixed(kernel), Mixed(reference));
+    }
+
+    #[test]
+    fn test_lower_precision() {
+        // One f32 ULP above 1.0 is many f64 ULPs above 1.0, but the comparison is in f32.
+        let above = 1.0 + f64::from(f32::EPSILON);
+        assert!(Ulps::default()
+            .epsilon(0.0)
+            .max_ulps(1)
+            .eq(&Mixed(1.0f32), &above));
+        assert!(Ulps::default()
+            .epsilon(0.0)
+            .max_ulps(0)
+            .ne(&Mixed(1.0f32), &above));
+
+        // Values that only differ beyond f32 precision are equal.
+        assert_eq!(Mixed(1.0f32), 1.0 + 1e-12);
+        assert_ne!(Mixed(1.0f32), 1.0 + 1e-6);
+    }
+
+    #[test]
+    fn test_epsilon_type() {
+        let epsilon: f32 = <Mixed<f64> as AbsDiffEq<f32>>::default_epsilon();
+        assert_eq!(epsilon, f32::EPSILON);
+        assert!(AbsDiff::default()
+            .epsilon(0.5f32)
+            .eq(&Mixed(1.0f64), &1.4f32));
+        assert!(Relative::default()
+            .max_relative(0.1f32)
+            .eq(&Mixed(10.0f32), &10.5f64));
+    }
+}
+
+mod test_integers {
+    use super::*;
+
+    #[test]
+    fn test_integer_float() {
+        assert_abs_diff_eq!(Mixed(3), 3.0f64);
+        assert_abs_diff_eq!(Mixed(3u8), 3.05f32, epsilon = 0.1f32);
+        assert_relative_ne!(Mixed(3i64), 3.1f64);
+        assert_ulps_eq!(Mixed(1usize << 40), (1u64 << 40) as f64);
+    }
+
+    #[test]
+    fn test_float_integer() {
+        assert_abs_diff_eq!(Mixed(3.0f64), 3);
+        assert_abs_diff_ne!(Mixed(2.5f32), 3u16);
+        assert_relative_eq!(Mixed(100.5f64), 100i32, max_relative = 0.01);
+        assert_eq!(Mixed(-2.0f32), -2i8);
+    }
+
+    #[test]
+    fn test_wrapped_right() {
+        assert_abs_diff_eq!(Mixed(3.0f64), Mixed(3));
+        assert_relative_ne!(Mixed(3u32), Mixed(3.1f32));
+        assert!(3 == Mixed(3.0f64));
+        assert!(2.5f32 != Mixed(3u16));
+    }
+
+    #[test]
+    fn test_debug() {
+        assert_eq!(format!("{:?}", Mixed(1.5f32)), "1.5");
+    }
+}
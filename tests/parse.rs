@@ -0,0 +1,136 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+
+mod test_parse {
+    use approx::{AbsDiff, ParseToleranceErrorKind, Relative, Ulps};
+
+    #[test]
+    fn test_basic() {
+        assert_eq!(
+            "abs=0.5".parse(),
+            Ok(AbsDiff::<f64>::default().epsilon(0.5))
+        );
+        assert_eq!(
+            "rel=1e-9,abs=1e-12".parse(),
+            Ok(Relative::<f64>::default().epsilon(1e-12).max_relative(1e-9))
+        );
+        assert_eq!("ulps=4".parse(), Ok(Ulps::<f32>::default().max_ulps(4)));
+        assert_eq!(
+            " abs = 1e-6 , ulps = 8 ".parse(),
+            Ok(Ulps::<f32>::default().epsilon(1e-6).max_ulps(8))
+        );
+    }
+
+    #[test]
+    fn test_defaults() {
+        assert_eq!("".parse(), Ok(AbsDiff::<f64>::default()));
+        assert_eq!("  ".parse(), Ok(Relative::<f32>::default()));
+        assert_eq!(
+            "rel=0.5".parse(),
+            Ok(Relative::<f64>::default().max_relative(0.5))
+        );
+    }
+
+    #[test]
+    fn test_empty_item() {
+        let error = "abs=1e-12,,rel=1e-9".parse::<Relative<f64>>().unwrap_err();
+        assert_eq!(error.kind(), ParseToleranceErrorKind::EmptyItem);
+        assert_eq!(error.span(), 10..10);
+
+        let error = "abs=1e-12, ".parse::<Relative<f64>>().unwrap_err();
+        assert_eq!(error.kind(), ParseToleranceErrorKind::EmptyItem);
+        assert_eq!(error.span(), 10..11);
+    }
+
+    #[test]
+    fn test_missing_equals() {
+        let error = "abs=1e-12, rel".parse::<Relative<f64>>().unwrap_err();
+        assert_eq!(error.kind(), ParseToleranceErrorKind::MissingEquals);
+        assert_eq!(error.span(), 11..14);
+        assert_eq!(
+            error.to_string(),
+            "expected `=` after the key at bytes 11..14"
+        );
+    }
+
+    #[test]
+    fn test_unknown_key() {
+        let error = "ulps=4".parse::<Relative<f64>>().unwrap_err();
+        assert_eq!(error.kind(), ParseToleranceErrorKind::UnknownKey);
+        assert_eq!(error.span(), 0..4);
+        assert_eq!(
+            error.to_string(),
+            "unknown key, expected one of `abs`, `rel` at bytes 0..4"
+        );
+    }
+
+    #[test]
+    fn test_duplicate_key() {
+        let error = "abs=1, abs =2".parse::<AbsDiff<f64>>().unwrap_err();
+        assert_eq!(error.kind(), ParseToleranceErrorKind::DuplicateKey);
+        assert_eq!(error.span(), 7..10);
+        assert_eq!(error.to_string(), "duplicate key `abs` at bytes 7..10");
+    }
+
+    #[test]
+    fn test_invalid_value() {
+        let error = "ulps=-1".parse::<Ulps<f64>>().unwrap_err();
+        assert_eq!(error.kind(), ParseToleranceErrorKind::InvalidValue);
+        assert_eq!(error.span(), 5..7);
+        assert_eq!(error.to_string(), "invalid value for `ulps` at bytes 5..7");
+
+        let error = "abs=".parse::<Ulps<f64>>().unwrap_err();
+        assert_eq!(error.kind(), ParseToleranceErrorKind::InvalidValue);
+        assert_eq!(error.span(), 4..4);
+    }
+}
+
+mod test_display {
+    use approx::{AbsDiff, Relative, Ulps};
+
+    #[test]
+    fn test_basic() {
+        assert_eq!(
+            AbsDiff::<f64>::default().epsilon(0.5).to_string(),
+            "abs=5e-1"
+        );
+        assert_eq!(
+            Relative::<f64>::default()
+                .epsilon(1e-12)
+                .max_relative(1e-9)
+                .to_string(),
+            "abs=1e-12,rel=1e-9"
+        );
+        assert_eq!(
+            Ulps::<f32>::default().to_string(),
+            format!("abs={:e},ulps=4", f32::EPSILON)
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let abs_diff = AbsDiff::<f64>::default().epsilon(0.1 + 0.2);
+        assert_eq!(abs_diff.to_string().parse(), Ok(abs_diff));
+
+        let relative = Relative::<f32>::default().max_relative(1.0 / 3.0);
+        assert_eq!(relative.to_string().parse(), Ok(relative));
+
+        let ulps = Ulps::<f64>::default()
+            .epsilon(f64::MIN_POSITIVE)
+            .max_ulps(u32::MAX);
+        assert_eq!(ulps.to_string().parse(), Ok(ulps));
+    }
+}
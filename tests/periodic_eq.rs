@@ -0,0 +1,147 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![no_std]
+
+#[macro_use]
+extern crate approx;
+
+mod test_f32 {
+    use core::f32::consts::PI;
+
+    #[test]
+    fn test_basic() {
+        assert_angle_eq!(1.0f32, 1.0f32);
+        assert_angle_ne!(1.0f32, 2.0f32);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_eq() {
+        assert_angle_eq!(1.0f32, 2.0f32);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_ne() {
+        assert_angle_ne!(1.0f32, 1.0f32);
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_angle_eq!(PI, -PI, epsilon = 1e-6);
+        assert_angle_eq!(PI - 1e-3, 1e-3 - PI, epsilon = 1e-2);
+        assert_angle_eq!(0.0f32, 2.0 * PI, epsilon = 1e-6);
+        assert_angle_eq!(0.1f32, 0.1 + 4.0 * PI, epsilon = 1e-5);
+        assert_angle_ne!(0.0f32, PI);
+    }
+
+    #[test]
+    fn test_degrees() {
+        assert_angle_eq!(359.5f32, -0.5f32, period = 360.0);
+        assert_angle_eq!(-180.0f32, 180.0f32, period = 360.0);
+        assert_angle_ne!(0.0f32, 180.0f32, period = 360.0);
+    }
+}
+
+mod test_f64 {
+    use core::f64::consts::PI;
+
+    #[test]
+    fn test_basic() {
+        assert_angle_eq!(1.0f64, 1.0f64);
+        assert_angle_ne!(1.0f64, 2.0f64);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_eq() {
+        assert_angle_eq!(1.0f64, 2.0f64);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_ne() {
+        assert_angle_ne!(1.0f64, 1.0f64);
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_angle_eq!(PI, -PI);
+        assert_angle_eq!(PI - 1e-3, 1e-3 - PI, epsilon = 1e-2);
+        assert_angle_eq!(0.0f64, 2.0 * PI);
+        assert_angle_eq!(-0.1f64, 2.0 * PI - 0.1, epsilon = 1e-12);
+        assert_angle_ne!(0.0f64, PI);
+        assert_angle_ne!(PI - 1e-3, 1e-3 - PI);
+    }
+
+    #[test]
+    fn test_degrees() {
+        assert_angle_eq!(359.5f64, -0.5f64, period = 360.0);
+        assert_angle_eq!(720.0f64, 0.0f64, period = 360.0);
+        assert_angle_ne!(0.0f64, 180.0f64, period = 360.0);
+    }
+
+    #[test]
+    fn test_relative() {
+        assert_angle_ne!(1000.0f64, 1000.0000001 + 2.0 * PI);
+        assert_angle_eq!(1000.0f64, 1000.0000001 + 2.0 * PI, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn test_ulps() {
+        assert_angle_eq!(0.25f64, 1.25f64, period = 1.0, max_ulps = 0);
+        assert_angle_ne!(0.25f64, 1.26f64, period = 1.0, max_ulps = 4);
+    }
+
+    #[test]
+    fn test_nan() {
+        assert_angle_ne!(f64::NAN, 0.0f64);
+        assert_angle_ne!(0.0f64, f64::NAN);
+    }
+}
+
+mod test_periodic {
+    use approx::Periodic;
+
+    #[test]
+    fn test_degrees() {
+        assert!(Periodic::degrees().eq(&359.0f64, &-1.0f64));
+        assert!(Periodic::degrees().ne(&1.0f32, &-1.0f32));
+    }
+}
+
+mod test_slice {
+    use core::f64::consts::PI;
+
+    #[test]
+    fn test_basic() {
+        assert_angle_eq!([0.0f64, PI][..], [2.0 * PI, -PI][..]);
+        assert_angle_ne!([0.0f64, PI][..], [PI, 0.0][..]);
+        assert_angle_ne!([0.0f64, PI][..], [0.0f64][..]);
+    }
+}
+
+#[cfg(feature = "num-complex")]
+mod test_complex {
+    extern crate num_complex;
+    use self::num_complex::Complex;
+
+    #[test]
+    fn test_phase() {
+        assert_angle_eq!(Complex::new(-1.0f64, 1e-17), Complex::new(-1.0f64, -1e-17));
+        assert_angle_eq!(Complex::new(1.0f64, 1.0), Complex::new(3.0f64, 3.0));
+        assert_angle_ne!(Complex::new(1.0f64, 0.0), Complex::new(-1.0f64, 0.0));
+    }
+}
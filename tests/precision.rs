@@ -0,0 +1,92 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "std")]
+
+#[macro_use]
+extern crate approx;
+
+mod test_sig_figs {
+    #[test]
+    fn test_basic() {
+        assert_sig_figs_eq!(1234.0f64, 1231.0f64, sig_figs = 3);
+        assert_sig_figs_ne!(1234.0f64, 1226.0f64, sig_figs = 3);
+        assert_sig_figs_eq!(1.0f32, 1.0000001f32, sig_figs = 6);
+        assert_sig_figs_ne!(1.0f32, 1.00001f32, sig_figs = 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "diff  = 8.0 (half a unit = 5.0)")]
+    fn test_basic_panic_eq() {
+        assert_sig_figs_eq!(1234.0f64, 1226.0f64, sig_figs = 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_ne() {
+        assert_sig_figs_ne!(1234.0f64, 1231.0f64, sig_figs = 3);
+    }
+
+    #[test]
+    fn test_orders_of_magnitude() {
+        assert_sig_figs_eq!(1.23456e-300f64, 1.23461e-300f64, sig_figs = 5);
+        assert_sig_figs_ne!(1.23456e-300f64, 1.23467e-300f64, sig_figs = 5);
+        assert_sig_figs_eq!(1.23456e300f64, 1.23461e300f64, sig_figs = 5);
+        assert_sig_figs_ne!(1.23456e300f64, 1.23467e300f64, sig_figs = 5);
+    }
+
+    #[test]
+    fn test_zero() {
+        assert_sig_figs_eq!(0.0f64, 0.0f64, sig_figs = 6);
+        assert_sig_figs_eq!(0.0f64, -0.0f64, sig_figs = 6);
+        assert_sig_figs_ne!(0.0f64, 1e-300f64, sig_figs = 6);
+    }
+
+    #[test]
+    fn test_infinity_nan() {
+        assert_sig_figs_eq!(f64::INFINITY, f64::INFINITY, sig_figs = 6);
+        assert_sig_figs_ne!(f64::INFINITY, f64::MAX, sig_figs = 1);
+        assert_sig_figs_ne!(f64::NAN, f64::NAN, sig_figs = 1);
+        assert_sig_figs_ne!(1.0f64, f64::NAN, sig_figs = 1);
+    }
+}
+
+mod test_places {
+    #[test]
+    fn test_basic() {
+        assert_places_eq!(1.234f64, 1.231f64, places = 2);
+        assert_places_ne!(1.234f64, 1.226f64, places = 2);
+        assert_places_eq!(1.0f32, 1.00000004f32, places = 7);
+        assert_places_eq!(1e6f64, 1e6f64 + 1e-5, places = 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "(half a unit = 0.005)")]
+    fn test_basic_panic_eq() {
+        assert_places_eq!(1.234f64, 1.226f64, places = 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_ne() {
+        assert_places_ne!(1.234f64, 1.231f64, places = 2);
+    }
+
+    #[test]
+    fn test_infinity_nan() {
+        assert_places_eq!(f64::INFINITY, f64::INFINITY, places = 2);
+        assert_places_ne!(f64::INFINITY, f64::NEG_INFINITY, places = 2);
+        assert_places_ne!(f64::NAN, f64::NAN, places = 2);
+    }
+}
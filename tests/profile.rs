@@ -0,0 +1,133 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+
+use approx::{AbsDiff, Relative, ToleranceProfile, Ulps, WithProfile};
+
+struct Geometry;
+
+impl ToleranceProfile<f64> for Geometry {
+    fn default_epsilon() -> f64 {
+        1e-9
+    }
+    fn default_max_relative() -> f64 {
+        1e-9
+    }
+    fn default_max_ulps() -> u32 {
+        16
+    }
+}
+
+struct Rendering;
+
+impl ToleranceProfile<f32> for Rendering {
+    fn default_epsilon() -> f32 {
+        1e-4
+    }
+    fn default_max_relative() -> f32 {
+        1e-4
+    }
+    fn default_max_ulps() -> u32 {
+        64
+    }
+}
+
+type Length = WithProfile<f64, Geometry>;
+type Colour = WithProfile<f32, Rendering>;
+
+mod test_with_profile {
+    use super::*;
+
+    #[test]
+    fn test_macros() {
+        assert_abs_diff_eq!(Length::new(0.0), Length::new(1e-10));
+        assert_abs_diff_ne!(Length::new(0.0), Length::new(1e-8));
+        assert_relative_eq!(Length::new(1e6), Length::new(1e6 + 1e-4));
+        assert_relative_ne!(Length::new(1e6), Length::new(1e6 + 1e-2));
+        assert_ulps_eq!(Length::new(1.0), Length::new(1.0 + 1e-10));
+
+        assert_abs_diff_eq!(Colour::new(0.5), Colour::new(0.50005));
+        assert_relative_ne!(Colour::new(0.5), Colour::new(0.501));
+    }
+
+    #[test]
+    fn test_options() {
+        assert_abs_diff_ne!(Length::new(0.0), Length::new(1e-10), epsilon = 1e-12);
+        assert_relative_eq!(Length::new(1.0), Length::new(1.1), max_relative = 0.1);
+        assert_ulps_ne!(
+            Length::new(1.0),
+            Length::new(1.0 + 1e-14),
+            epsilon = 0.0,
+            max_ulps = 4
+        );
+    }
+
+    #[test]
+    fn test_unchanged_defaults() {
+        assert_abs_diff_ne!(0.0, 1e-10);
+        assert_relative_ne!(1.0, 1.0 + 1e-10);
+    }
+
+    #[test]
+    fn test_slices() {
+        let a = [Length::new(1.0), Length::new(2.0)];
+        let b = [Length::new(1.0 + 1e-10), Length::new(2.0 - 1e-10)];
+        assert_relative_eq!(&a[..], &b[..]);
+    }
+
+    #[test]
+    fn test_debug() {
+        assert_eq!(format!("{:?}", Length::new(1.5)), "1.5");
+        assert_eq!(Length::new(1.5).into_inner(), 1.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "left  = 1.0")]
+    fn test_failure_message() {
+        assert_relative_eq!(Length::new(1.0), Length::new(1.1));
+    }
+}
+
+mod test_comparisons {
+    use super::*;
+
+    #[test]
+    fn test_with_profile() {
+        assert_eq!(
+            AbsDiff::<f64>::with_profile::<Geometry>(),
+            AbsDiff::default().epsilon(1e-9)
+        );
+        assert_eq!(
+            Relative::<f64>::with_profile::<Geometry>(),
+            Relative::default().epsilon(1e-9).max_relative(1e-9)
+        );
+        assert_eq!(
+            Ulps::<f32>::with_profile::<Rendering>(),
+            Ulps::default().epsilon(1e-4).max_ulps(64)
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(
+            Relative::<Length>::default(),
+            Relative::default().epsilon(1e-9).max_relative(1e-9)
+        );
+        assert!(Relative::<f64>::with_profile::<Geometry>().eq(&1.0, &(1.0 + 1e-10)));
+        let ulps = Ulps::<[f64]>::with_profile::<Geometry>();
+        assert!(ulps.eq(&[1.0, 2.0][..], &[1.0, 2.0 + 1e-10][..]));
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+
+mod test_relative {
+    #[test]
+    fn test_residual() {
+        let (a, b) = (1e10f64, 1e10 + 1e-3);
+        assert_relative_eq!(a - b, 0.0, max_relative = 1e-12, scale = a);
+        assert_relative_ne!(a - b, 0.0, max_relative = 1e-12);
+        assert_relative_ne!(a - b, 0.0, max_relative = 1e-14, scale = a);
+        assert_relative_eq!(a - b, 0.0, scale = a, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn test_epsilon() {
+        assert_relative_eq!(1e-3f64, 0.0, scale = 0.0, epsilon = 1e-2);
+        assert_relative_ne!(1e-3f64, 0.0, scale = 0.0);
+    }
+
+    #[test]
+    fn test_negative_scale() {
+        // The macros reject a negative scale, but the comparison uses its magnitude.
+        let relative = approx::Relative::default().max_relative(1e-6);
+        assert!(relative.scale(-1e4).eq(&1e-3f32, &0.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_residual_panic_eq() {
+        assert_relative_eq!(1.0f64, 0.0, max_relative = 1e-12, scale = 1e10);
+    }
+}
+
+mod test_ulps {
+    #[test]
+    fn test_residual() {
+        let (a, b) = (1e10f64, 1e10 + 2e-6);
+        assert_ulps_eq!(a - b, 0.0, scale = a);
+        assert_ulps_ne!(a - b, 0.0);
+        assert_ulps_eq!(a - b, 0.0, scale = 1e4, max_ulps = 1 << 31);
+        assert_ulps_ne!(a - b, 0.0, scale = a, max_ulps = 0);
+    }
+
+    #[test]
+    fn test_zero_scale() {
+        assert_ulps_eq!(0.0f32, 0.0, scale = 0.0);
+        assert_ulps_ne!(1e-3f32, 0.0, scale = 0.0);
+    }
+}
+
+mod test_approx_zero {
+    #[test]
+    fn test_basic() {
+        let (a, b) = (1e10f64, 1e10 + 1e-3);
+        assert_approx_zero!(0.0f64);
+        assert_approx_zero!(a - b, max_relative = 1e-12, scale = a);
+        assert_approx_nonzero!(a - b);
+        assert_approx_nonzero!(1.0f32, scale = 1e3);
+    }
+
+    #[test]
+    #[should_panic(expected = "assert_approx_zero!(a - b, scale = a)")]
+    fn test_basic_panic() {
+        let (a, b) = (1e10f64, 1e10 + 1e-3);
+        assert_approx_zero!(a - b, scale = a);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_nonzero() {
+        assert_approx_nonzero!(1e-6f64, max_relative = 1e-12, scale = 1e10);
+    }
+
+    #[test]
+    fn test_slice() {
+        assert!(approx::Relative::default()
+            .max_relative(1e-12)
+            .scale(1e10)
+            .eq(&[1e-3f64, -1e-3][..], &[0.0, 0.0][..]));
+    }
+}
@@ -0,0 +1,261 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(all(feature = "serde", feature = "std"))]
+
+extern crate approx;
+#[macro_use]
+extern crate serde;
+extern crate serde_json;
+
+use approx::{serde_approx_eq, AbsDiff, Relative, Tolerance, Ulps};
+use serde::ser::{Error, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::{f32, f64};
+
+#[derive(Serialize)]
+struct Result {
+    label: String,
+    energy: f64,
+    weight: f32,
+    count: u32,
+}
+
+#[derive(Serialize)]
+struct Report {
+    results: Vec<Result>,
+    shape: Shape,
+    note: Option<String>,
+}
+
+#[derive(Serialize)]
+enum Shape {
+    Point,
+    Circle(f64),
+    Rect { width: f64, height: f64 },
+}
+
+fn result(energy: f64) -> Result {
+    Result {
+        label: "a".to_string(),
+        energy,
+        weight: 0.5,
+        count: 3,
+    }
+}
+
+fn report(energies: &[f64], shape: Shape) -> Report {
+    Report {
+        results: energies.iter().map(|&energy| result(energy)).collect(),
+        shape,
+        note: None,
+    }
+}
+
+fn relative() -> Tolerance<f64> {
+    Relative::<f64>::default().into()
+}
+
+fn paths(mismatches: &[approx::FieldMismatch]) -> Vec<&str> {
+    mismatches.iter().map(|m| &m.path[..]).collect()
+}
+
+mod test_serde_approx_eq {
+    use super::*;
+
+    #[test]
+    fn test_equal() {
+        let a = report(&[1.0, 2.0], Shape::Circle(1.0));
+        let b = report(&[1.0 + f64::EPSILON, 2.0], Shape::Circle(1.0));
+        assert!(serde_approx_eq(&a, &b, &relative()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_paths() {
+        let a = report(&[1.0, 2.0, 3.0, 4.0, 5.0], Shape::Point);
+        let b = report(&[1.0, 2.0, 3.0, 4.0, 5.5], Shape::Point);
+        let mismatches = serde_approx_eq(&a, &b, &relative()).unwrap();
+        assert_eq!(paths(&mismatches), [".results[4].energy"]);
+        assert_eq!(
+            mismatches[0].to_string(),
+            ".results[4].energy: left = 5.0, right = 5.5"
+        );
+    }
+
+    #[test]
+    fn test_tolerance() {
+        let (a, b) = (report(&[1.0], Shape::Point), report(&[1.1], Shape::Point));
+        assert_eq!(serde_approx_eq(&a, &b, &relative()).unwrap().len(), 1);
+
+        let abs = AbsDiff::<f64>::default().epsilon(0.2).into();
+        assert!(serde_approx_eq(&a, &b, &abs).unwrap().is_empty());
+
+        let ulps = Ulps::<f64>::default().max_ulps(4).into();
+        let c = report(&[1.0 + 2.0 * f64::EPSILON], Shape::Point);
+        assert!(serde_approx_eq(&a, &c, &ulps).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_f32() {
+        let tolerance = Relative::<f64>::default().max_relative(1e-6).into();
+        let mut b = result(1.0);
+        b.weight = 0.5 + f32::EPSILON;
+        assert!(serde_approx_eq(&result(1.0), &b, &tolerance)
+            .unwrap()
+            .is_empty());
+        b.weight = 0.5 + 1e-3;
+        assert_eq!(
+            paths(&serde_approx_eq(&result(1.0), &b, &tolerance).unwrap()),
+            [".weight"]
+        );
+    }
+
+    #[test]
+    fn test_exact_leaves() {
+        let mut b = result(1.0);
+        b.label = "b".to_string();
+        b.count = 4;
+        let mismatches = serde_approx_eq(&result(1.0), &b, &relative()).unwrap();
+        assert_eq!(paths(&mismatches), [".label", ".count"]);
+        assert_eq!(
+            mismatches[0].to_string(),
+            r#".label: left = "a", right = "b""#
+        );
+        assert_eq!(mismatches[1].to_string(), ".count: left = 3, right = 4");
+    }
+
+    #[test]
+    fn test_integers_and_floats() {
+        assert!(serde_approx_eq(&1, &1.0, &relative()).unwrap().is_empty());
+        assert!(!serde_approx_eq(&1, &1.5, &relative()).unwrap().is_empty());
+        assert!(!serde_approx_eq(&1, &2, &relative()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_signedness() {
+        assert!(serde_approx_eq(&1i32, &1u32, &relative())
+            .unwrap()
+            .is_empty());
+        assert!(serde_approx_eq(&(-1i64, 2u8), &(-1i8, 2i16), &relative())
+            .unwrap()
+            .is_empty());
+        assert!(!serde_approx_eq(&-1i32, &1u32, &relative())
+            .unwrap()
+            .is_empty());
+        assert!(!serde_approx_eq(&std::u64::MAX, &-1i64, &relative())
+            .unwrap()
+            .is_empty());
+
+        let mut a = BTreeMap::new();
+        a.insert(1u32, 1.0);
+        let mut b = BTreeMap::new();
+        b.insert(1i64, 1.0);
+        assert!(serde_approx_eq(&a, &b, &relative()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_values() {
+        let mismatches = serde_approx_eq(&1.0, &2.0, &relative()).unwrap();
+        assert_eq!(mismatches[0].to_string(), ".: left = 1.0, right = 2.0");
+    }
+
+    #[test]
+    fn test_sequences() {
+        let mismatches = serde_approx_eq(&[1.0, 2.0][..], &[1.0][..], &relative()).unwrap();
+        assert_eq!(
+            mismatches[0].to_string(),
+            "[1]: left = 2.0, right = <missing>"
+        );
+    }
+
+    #[test]
+    fn test_enums() {
+        let a = report(
+            &[],
+            Shape::Rect {
+                width: 1.0,
+                height: 2.0,
+            },
+        );
+        let b = report(
+            &[],
+            Shape::Rect {
+                width: 1.0,
+                height: 2.5,
+            },
+        );
+        let mismatches = serde_approx_eq(&a, &b, &relative()).unwrap();
+        assert_eq!(paths(&mismatches), [".shape.height"]);
+
+        let c = report(&[], Shape::Circle(1.0));
+        let mismatches = serde_approx_eq(&a, &c, &relative()).unwrap();
+        assert_eq!(
+            mismatches[0].to_string(),
+            ".shape: left = Rect { width: 1.0, height: 2.0 }, right = Circle(1.0)"
+        );
+    }
+
+    #[test]
+    fn test_options() {
+        let a = report(&[], Shape::Point);
+        let mut b = report(&[], Shape::Point);
+        b.note = Some("x".to_string());
+        let mismatches = serde_approx_eq(&a, &b, &relative()).unwrap();
+        assert_eq!(
+            mismatches[0].to_string(),
+            r#".note: left = None, right = Some("x")"#
+        );
+    }
+
+    #[test]
+    fn test_maps() {
+        let mut a = BTreeMap::new();
+        a.insert("x", 1.0);
+        a.insert("y", 2.0);
+        let mut b = a.clone();
+        b.insert("y", 2.5);
+        b.insert("z", 3.0);
+
+        let mismatches = serde_approx_eq(&a, &b, &relative()).unwrap();
+        assert_eq!(paths(&mismatches), [r#"["y"]"#, r#"["z"]"#]);
+        assert_eq!(mismatches[1].left, "<missing>");
+    }
+
+    #[test]
+    fn test_json() {
+        let a: serde_json::Value =
+            serde_json::from_str(r#"{ "results": [{ "energy": 1.0 }] }"#).unwrap();
+        let b: serde_json::Value =
+            serde_json::from_str(r#"{ "results": [{ "energy": 1.5 }] }"#).unwrap();
+        let mismatches = serde_approx_eq(&a, &b, &relative()).unwrap();
+        assert_eq!(paths(&mismatches), [r#"["results"][0]["energy"]"#]);
+    }
+
+    struct Unserializable;
+
+    impl Serialize for Unserializable {
+        fn serialize<S: Serializer>(&self, _: S) -> std::result::Result<S::Ok, S::Error> {
+            Err(S::Error::custom("not supported"))
+        }
+    }
+
+    #[test]
+    fn test_error() {
+        let error = serde_approx_eq(&Unserializable, &1.0, &relative()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "failed to serialize value: not supported"
+        );
+    }
+}
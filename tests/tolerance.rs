@@ -0,0 +1,179 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+
+mod test_tolerance {
+    use approx::{AbsDiff, Comparator, Relative, Tolerance, Ulps};
+
+    #[test]
+    fn test_exact() {
+        assert!(Tolerance::Exact.eq(&1.0f64, &1.0));
+        assert!(Tolerance::Exact.ne(&1.0f64, &(1.0 + f64::EPSILON)));
+        assert!(Tolerance::Exact.ne(&f64::NAN, &f64::NAN));
+    }
+
+    #[test]
+    fn test_abs() {
+        let tolerance = Tolerance::Abs { epsilon: 0.5f32 };
+        assert!(tolerance.eq(&1.0f32, &1.5));
+        assert!(tolerance.ne(&1.0f32, &1.6));
+    }
+
+    #[test]
+    fn test_relative() {
+        let tolerance = Tolerance::Relative {
+            epsilon: 0.0,
+            max_relative: 1e-6,
+        };
+        assert!(tolerance.eq(&1e10f64, &1.0000001e10));
+        assert!(tolerance.ne(&1e10f64, &1.00001e10));
+    }
+
+    #[test]
+    fn test_ulps() {
+        let tolerance = Tolerance::Ulps {
+            epsilon: 0.0,
+            max_ulps: 4,
+        };
+        assert!(tolerance.eq(&1e10f64, &(1e10 + 2e-6)));
+        assert!(tolerance.ne(&1e10f64, &(1e10 + 1e-4)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_any_all() {
+        let abs = Tolerance::Abs { epsilon: 1e-12 };
+        let ulps = Tolerance::Ulps {
+            epsilon: 0.0,
+            max_ulps: 4,
+        };
+        let any = Tolerance::Any(vec![abs.clone(), ulps.clone()]);
+        assert!(any.eq(&1e-13f64, &0.0));
+        assert!(any.eq(&1e10f64, &(1e10 + 2e-6)));
+        assert!(any.ne(&1e-11f64, &0.0));
+
+        let all = Tolerance::All(vec![abs, ulps]);
+        assert!(all.ne(&1e-13f64, &0.0));
+        assert!(all.eq(&1.0f64, &1.0));
+
+        assert!(Tolerance::Any(vec![]).ne(&1.0f64, &1.0));
+        assert!(Tolerance::All(vec![]).eq(&1.0f64, &2.0));
+    }
+
+    #[test]
+    fn test_slice() {
+        let tolerance = Tolerance::Abs { epsilon: 0.5 };
+        assert!(tolerance.eq(&[1.0f64, 2.0][..], &[1.5, 2.5][..]));
+        assert!(tolerance.ne(&[1.0f64, 2.0][..], &[1.5, 2.6][..]));
+    }
+
+    #[test]
+    fn test_comparator() {
+        let tolerance = Tolerance::Abs { epsilon: 0.5 };
+        assert!(tolerance.compare(&1.0f64, &1.5));
+        assert!(!approx::Not(tolerance).compare(&1.0f64, &1.5));
+    }
+
+    #[test]
+    fn test_from() {
+        assert_eq!(
+            Tolerance::from(AbsDiff::<f64>::default().epsilon(0.5)),
+            Tolerance::Abs { epsilon: 0.5 }
+        );
+        assert_eq!(
+            Tolerance::from(Relative::<f64>::default().max_relative(0.5)),
+            Tolerance::Relative {
+                epsilon: f64::EPSILON,
+                max_relative: 0.5,
+            }
+        );
+        assert_eq!(
+            Tolerance::from(Ulps::<f32>::default()),
+            Tolerance::Ulps {
+                epsilon: f32::EPSILON,
+                max_ulps: 4,
+            }
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+mod test_serde {
+    extern crate serde_json;
+
+    use approx::{AbsDiff, Relative, Tolerance, Ulps};
+
+    #[test]
+    fn test_tolerance() {
+        let tolerance: Tolerance<f64> = serde_json::from_str(
+            r#"{ "any": [{ "abs": { "epsilon": 1e-12 } }, { "ulps": { "epsilon": 0.0, "max_ulps": 4 } }] }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            tolerance,
+            Tolerance::Any(vec![
+                Tolerance::Abs { epsilon: 1e-12 },
+                Tolerance::Ulps {
+                    epsilon: 0.0,
+                    max_ulps: 4,
+                },
+            ])
+        );
+        assert!(tolerance.eq(&1e-13f64, &0.0));
+
+        let exact: Tolerance<f64> = serde_json::from_str(r#""exact""#).unwrap();
+        assert_eq!(exact, Tolerance::Exact);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let tolerance = Tolerance::All(vec![
+            Tolerance::Exact,
+            Tolerance::Relative {
+                epsilon: 0.0f32,
+                max_relative: 1e-6,
+            },
+        ]);
+        let json = serde_json::to_string(&tolerance).unwrap();
+        assert_eq!(
+            json,
+            r#"{"all":["exact",{"relative":{"epsilon":0.0,"max_relative":0.000001}}]}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Tolerance<f32>>(&json).unwrap(),
+            tolerance
+        );
+    }
+
+    #[test]
+    fn test_structs() {
+        let abs: AbsDiff<f64> = serde_json::from_str(r#"{ "epsilon": 0.5 }"#).unwrap();
+        assert_eq!(abs, AbsDiff::default().epsilon(0.5));
+
+        let relative = Relative::<f64>::default().max_relative(0.5);
+        let json = serde_json::to_string(&relative).unwrap();
+        assert_eq!(
+            json,
+            r#"{"epsilon":2.220446049250313e-16,"max_relative":0.5}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Relative<f64>>(&json).unwrap(),
+            relative
+        );
+
+        let ulps: Ulps<f32> = serde_json::from_str(r#"{ "epsilon": 0.0, "max_ulps": 8 }"#).unwrap();
+        assert_eq!(ulps, Ulps::default().epsilon(0.0).max_ulps(8));
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+
+mod test_up_to_sign {
+    use approx::{AbsDiff, Relative, Ulps};
+
+    #[test]
+    fn test_basic() {
+        let v = [0.6f64, -0.8];
+        assert!(AbsDiff::default().eq_up_to_sign(&v, &[0.6, -0.8]));
+        assert!(AbsDiff::default().eq_up_to_sign(&v, &[-0.6, 0.8]));
+        assert!(!AbsDiff::default().eq_up_to_sign(&v, &[-0.6, -0.8]));
+        assert!(!AbsDiff::default().eq_up_to_sign(&v, &[0.6]));
+    }
+
+    #[test]
+    fn test_tolerance() {
+        let v = [0.6f64, -0.8];
+        assert!(Relative::default()
+            .max_relative(1e-6)
+            .eq_up_to_sign(&v, &[-0.6000001, 0.8]));
+        assert!(!Relative::default().eq_up_to_sign(&v, &[-0.6000001, 0.8]));
+        assert!(Ulps::default().eq_up_to_sign(&[1.0f32, 2.0], &[-1.0, -2.0000002]));
+    }
+}
+
+mod test_up_to_scale {
+    use approx::{AbsDiff, Relative, Ulps};
+
+    #[test]
+    fn test_basic() {
+        let v = [1.0f64, 2.0, 3.0];
+        assert!(AbsDiff::default().eq_up_to_scale(&v, &[1.0, 2.0, 3.0]));
+        assert!(AbsDiff::default().eq_up_to_scale(&v, &[2.0, 4.0, 6.0]));
+        assert!(AbsDiff::default().eq_up_to_scale(&v, &[-0.5, -1.0, -1.5]));
+        assert!(!AbsDiff::default().eq_up_to_scale(&v, &[1.0, 2.0, 4.0]));
+        assert!(!AbsDiff::default().eq_up_to_scale(&v, &[1.0, 2.0]));
+    }
+
+    #[test]
+    fn test_zero() {
+        assert!(AbsDiff::default().eq_up_to_scale(&[0.0f64, 0.0], &[0.0, 0.0]));
+        assert!(!AbsDiff::default().eq_up_to_scale(&[0.0f64, 0.0], &[1.0, 2.0]));
+        assert!(!AbsDiff::default().eq_up_to_scale(&[1.0f64, 2.0], &[0.0, 0.0]));
+        assert!(AbsDiff::<f64>::default().eq_up_to_scale(&[], &[]));
+    }
+
+    #[test]
+    fn test_nan() {
+        assert!(!Relative::default().eq_up_to_scale(&[1.0f64, 2.0], &[1.0, f64::NAN]));
+        assert!(!Relative::default().eq_up_to_scale(&[f64::NAN], &[f64::NAN]));
+    }
+
+    #[test]
+    fn test_tolerance() {
+        let v = [1e10f64, 2e10, 3e10];
+        assert!(Relative::default().eq_up_to_scale(&v, &[1.0, 2.0, 3.0]));
+        assert!(Relative::default()
+            .max_relative(1e-6)
+            .eq_up_to_scale(&v, &[1.0, 2.0000001, 3.0]));
+        assert!(!Relative::default().eq_up_to_scale(&v, &[1.0, 2.0000001, 3.0]));
+        assert!(Ulps::default().eq_up_to_scale(&[3.0f32, 6.0], &[1.0, 2.0]));
+    }
+}
+
+#[cfg(feature = "std")]
+mod test_unordered {
+    use approx::{AbsDiff, Relative, Ulps};
+
+    #[test]
+    fn test_basic() {
+        let v = [3.0f64, 1.0, 2.0];
+        assert!(AbsDiff::default().unordered_eq(&v, &[1.0, 2.0, 3.0]));
+        assert!(AbsDiff::default().unordered_eq(&v, &[3.0, 1.0, 2.0]));
+        assert!(!AbsDiff::default().unordered_eq(&v, &[1.0, 2.0, 4.0]));
+        assert!(!AbsDiff::default().unordered_eq(&v, &[1.0, 2.0]));
+        assert!(AbsDiff::<f64>::default().unordered_eq(&[], &[]));
+    }
+
+    #[test]
+    fn test_multiset() {
+        assert!(AbsDiff::default().unordered_eq(&[1.0f64, 1.0, 2.0], &[1.0, 2.0, 1.0]));
+        assert!(!AbsDiff::default().unordered_eq(&[1.0f64, 1.0, 2.0], &[1.0, 2.0, 2.0]));
+    }
+
+    #[test]
+    fn test_optimal_matching() {
+        // A greedy matching would pair 1.0 with 1.4, leaving 1.5 without a partner
+        let lhs = [1.0f64, 1.5];
+        let rhs = [1.4f64, 0.6];
+        assert!(AbsDiff::default().epsilon(0.5).unordered_eq(&lhs, &rhs));
+        assert!(AbsDiff::default().epsilon(0.5).unordered_eq(&rhs, &lhs));
+        assert!(!AbsDiff::default().epsilon(0.3).unordered_eq(&lhs, &rhs));
+    }
+
+    #[test]
+    fn test_tolerance() {
+        let v = [1e10f64, 2e10];
+        assert!(Relative::default()
+            .max_relative(1e-6)
+            .unordered_eq(&v, &[2.0000001e10, 1e10]));
+        assert!(!Relative::default().unordered_eq(&v, &[2.0000001e10, 1e10]));
+        assert!(Ulps::default().unordered_eq(&[1.0f32, 2.0], &[2.0000002, 1.0]));
+    }
+}
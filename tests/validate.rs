@@ -0,0 +1,151 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate approx;
+
+mod test_newtypes {
+    use approx::{Epsilon, InvalidToleranceErrorKind, MaxRelative};
+
+    #[test]
+    fn test_epsilon() {
+        assert_eq!(Epsilon::new(1e-9f64).map(Epsilon::get), Ok(1e-9));
+        assert_eq!(Epsilon::new(0.0f32).map(Epsilon::get), Ok(0.0));
+
+        let error = Epsilon::new(-1e-9f64).unwrap_err();
+        assert_eq!(error.kind(), InvalidToleranceErrorKind::Negative);
+        assert_eq!(error.name(), "epsilon");
+        assert_eq!(error.to_string(), "`epsilon` must not be negative");
+
+        let error = Epsilon::new(f64::NAN).unwrap_err();
+        assert_eq!(error.kind(), InvalidToleranceErrorKind::NaN);
+        assert_eq!(error.to_string(), "`epsilon` must not be NaN");
+
+        let error = Epsilon::new(f32::INFINITY).unwrap_err();
+        assert_eq!(error.kind(), InvalidToleranceErrorKind::Infinite);
+        assert_eq!(error.to_string(), "`epsilon` must be finite");
+    }
+
+    #[test]
+    fn test_max_relative() {
+        assert_eq!(MaxRelative::new(1e-6f64).map(MaxRelative::get), Ok(1e-6));
+
+        let error = MaxRelative::new(-0.5f32).unwrap_err();
+        assert_eq!(error.kind(), InvalidToleranceErrorKind::Negative);
+        assert_eq!(error.to_string(), "`max_relative` must not be negative");
+        assert_eq!(
+            MaxRelative::new(f64::NEG_INFINITY).unwrap_err().kind(),
+            InvalidToleranceErrorKind::Negative
+        );
+    }
+}
+
+mod test_builders {
+    use approx::{AbsDiff, InvalidToleranceErrorKind, Relative, Ulps};
+
+    #[test]
+    fn test_valid() {
+        assert_eq!(
+            AbsDiff::<f64>::default().try_epsilon(0.5),
+            Ok(AbsDiff::default().epsilon(0.5))
+        );
+        assert_eq!(
+            Relative::<f64>::default()
+                .try_epsilon(1e-12)
+                .and_then(|r| r.try_max_relative(1e-9)),
+            Ok(Relative::default().epsilon(1e-12).max_relative(1e-9))
+        );
+        assert_eq!(
+            Ulps::<f32>::default().try_epsilon(0.0),
+            Ok(Ulps::default().epsilon(0.0))
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        let error = AbsDiff::<f64>::default().try_epsilon(-1.0).unwrap_err();
+        assert_eq!(error.kind(), InvalidToleranceErrorKind::Negative);
+
+        let error = Relative::<f64>::default()
+            .try_max_relative(f64::NAN)
+            .unwrap_err();
+        assert_eq!(error.kind(), InvalidToleranceErrorKind::NaN);
+        assert_eq!(error.name(), "max_relative");
+
+        let error = Ulps::<f32>::default()
+            .try_epsilon(f32::INFINITY)
+            .unwrap_err();
+        assert_eq!(error.kind(), InvalidToleranceErrorKind::Infinite);
+    }
+}
+
+#[cfg(debug_assertions)]
+mod test_macros {
+    #[test]
+    fn test_valid() {
+        assert_relative_eq!(1.0f64, 1.0, epsilon = 0.0, max_relative = 1e-9);
+        assert_ulps_eq!(1.0f32, 1.0, epsilon = 1e-6, max_ulps = 0);
+        assert_approx_zero!(1e-6f64, scale = 1e10);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "invalid tolerance `epsilon = -1e-9`: `epsilon` must not be negative"
+    )]
+    fn test_negative_epsilon() {
+        let _ = abs_diff_eq!(1.0f64, 1.0, epsilon = -1e-9);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "invalid tolerance `max_relative = f32::NAN`: `max_relative` must not be NaN"
+    )]
+    fn test_nan_max_relative() {
+        let _ = relative_eq!(1.0f32, 1.0, max_relative = f32::NAN);
+    }
+
+    #[test]
+    #[should_panic(expected = "`epsilon` must be finite")]
+    fn test_infinite_epsilon() {
+        let epsilon = f64::INFINITY;
+        assert_ulps_ne!(1.0f64, 2.0, epsilon = epsilon);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid tolerance `scale = f64::NAN`: `scale` must not be NaN")]
+    fn test_nan_scale() {
+        let _ = relative_eq!(1e-6f64, 0.0, scale = f64::NAN);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid tolerance `period = -360.0`: `period` must not be negative")]
+    fn test_negative_period() {
+        let _ = angle_eq!(359.0f64, -1.0, period = -360.0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[should_panic(
+        expected = "invalid tolerance `max_decibels = -0.1`: `max_decibels` must not be negative"
+    )]
+    fn test_negative_max_decibels() {
+        let _ = log_relative_eq!(1.0f64, 1.0, max_decibels = -0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid tolerance `rel = -1e-6`: `rel` must not be negative")]
+    fn test_approx_eq() {
+        let _ = approx_eq!(1.0f64, 1.0, abs = 1e-12, rel = -1e-6);
+    }
+}